@@ -0,0 +1,133 @@
+// Generates the table of guess rules from rules.toml when the toml-rules feature is enabled.
+// See src/rules.rs for the types the generated code uses.
+
+#[cfg(feature = "toml-rules")]
+mod toml_rules {
+    use std::env;
+    use std::fmt::Write;
+    use std::fs;
+    use std::path::PathBuf;
+
+    const HANDLERS: [(&str, &str); 27] = [
+        ("pelican", "Pelican"),
+        ("gatsby", "Gatsby"),
+        ("nikola", "Nikola"),
+        ("discourse", "Discourse"),
+        ("phpbb", "Phpbb"),
+        ("vbulletin", "Vbulletin"),
+        ("mediawiki", "Mediawiki"),
+        ("dokuwiki", "Dokuwiki"),
+        ("mastodon", "Mastodon"),
+        ("pleroma", "Pleroma"),
+        ("pixelfed", "Pixelfed"),
+        ("writefreely", "Writefreely"),
+        ("tumblr", "Tumblr"),
+        ("wordpress", "Wordpress"),
+        ("nitter", "Nitter"),
+        ("lemmy", "Lemmy"),
+        ("shopify", "Shopify"),
+        ("weebly", "Weebly"),
+        ("livejournal", "Livejournal"),
+        ("dreamwidth", "Dreamwidth"),
+        ("typepad", "Typepad"),
+        ("grav", "Grav"),
+        ("buttondown", "Buttondown"),
+        ("mailchimp", "Mailchimp"),
+        ("hugo", "Hugo"),
+        ("forem", "Forem"),
+        ("medium", "Medium"),
+    ];
+
+    pub fn generate() {
+        println!("cargo:rerun-if-env-changed=FEEDFINDER_RULES");
+        let path = match env::var_os("FEEDFINDER_RULES") {
+            Some(path) => PathBuf::from(path),
+            None => PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").unwrap()).join("rules.toml"),
+        };
+        println!("cargo:rerun-if-changed={}", path.display());
+
+        let content = fs::read_to_string(&path)
+            .unwrap_or_else(|err| panic!("unable to read {}: {}", path.display(), err));
+        let table = content
+            .parse::<toml::Value>()
+            .unwrap_or_else(|err| panic!("unable to parse {}: {}", path.display(), err));
+        let rules = table
+            .get("rule")
+            .and_then(|rules| rules.as_array())
+            .unwrap_or_else(|| panic!("{} has no [[rule]] tables", path.display()));
+
+        let mut code = String::from("pub(crate) const TOML_RULES: &[GuessRule] = &[\n");
+        for rule in rules {
+            let name = rule
+                .get("name")
+                .and_then(|name| name.as_str())
+                .unwrap_or_else(|| panic!("rule without a name in {}", path.display()));
+            writeln!(code, "    GuessRule {{").unwrap();
+            writeln!(code, "        name: {:?},", name).unwrap();
+            for key in &["generators", "markup", "markup_all", "hosts"] {
+                writeln!(code, "        {}: &{},", key, strings(rule, key, name)).unwrap();
+            }
+            writeln!(code, "        action: {},", action(rule, name)).unwrap();
+            writeln!(code, "    }},").unwrap();
+        }
+        code.push_str("];\n");
+
+        let out = PathBuf::from(env::var_os("OUT_DIR").unwrap()).join("rules.rs");
+        fs::write(&out, code).expect("unable to write generated rules");
+    }
+
+    // An array of strings as a Rust array literal, empty if the key is missing
+    fn strings(rule: &toml::Value, key: &str, name: &str) -> String {
+        let values = match rule.get(key) {
+            Some(values) => values
+                .as_array()
+                .unwrap_or_else(|| panic!("{} of rule {} is not an array", key, name))
+                .iter()
+                .map(|value| {
+                    let value = value
+                        .as_str()
+                        .unwrap_or_else(|| panic!("{} of rule {} has a non-string", key, name));
+                    format!("{:?}", value)
+                })
+                .collect::<Vec<_>>(),
+            None => Vec::new(),
+        };
+        format!("[{}]", values.join(", "))
+    }
+
+    fn action(rule: &toml::Value, name: &str) -> String {
+        let string = |key: &str| rule.get(key).and_then(|value| value.as_str());
+        let actions = ["segments", "root", "versioned_root", "handler"]
+            .iter()
+            .filter(|key| rule.get(**key).is_some())
+            .count();
+        if actions != 1 {
+            panic!("rule {} must have exactly one action", name);
+        }
+
+        if rule.get("segments").is_some() {
+            format!(
+                "GuessAction::Segments(&{})",
+                strings(rule, "segments", name)
+            )
+        } else if let Some(path) = string("root") {
+            format!("GuessAction::Root({:?})", path)
+        } else if let Some(path) = string("versioned_root") {
+            format!("GuessAction::VersionedRoot({:?})", path)
+        } else if let Some(handler) = string("handler") {
+            let variant = HANDLERS
+                .iter()
+                .find(|(key, _)| *key == handler)
+                .map(|(_, variant)| variant)
+                .unwrap_or_else(|| panic!("rule {} has unknown handler {}", name, handler));
+            format!("GuessAction::Handler(GuessHandler::{})", variant)
+        } else {
+            panic!("action of rule {} is not a string", name)
+        }
+    }
+}
+
+fn main() {
+    #[cfg(feature = "toml-rules")]
+    toml_rules::generate();
+}
@@ -1,5 +1,3 @@
-use feedfinder;
-
 use std::io::Read;
 use url::Url;
 
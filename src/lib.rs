@@ -10,18 +10,67 @@
 //!
 //! `feedfinder` can find feeds from these sources:
 //!
+//! * The content itself, when it is an RSS (including RSS 1.0) or Atom feed rather than HTML
+//! * The content itself, when it is an OPML outline like a blogroll, and optionally the feeds it lists
 //! * Linked via the `<link>` tag in the HTML
-//! * Linked via `<a>` tag in the HTML
+//! * Comment feeds linked via the `<link rel="replies">` tag in the HTML
+//! * The canonical feed of hub pages declared via the `<link rel="self">` tag in the HTML
+//! * Listed in an Atom Publishing Protocol service document linked from the HTML
+//! * Listed in a Really Simple Discovery document linked via `<link rel="EditURI">`
+//! * Listed in a discovery document linked from the HTML, like `/.well-known/feeds` or `/.well-known/host-meta`
+//! * Linked via `<a>` tag in the HTML, including links wrapping a feed icon
+//! * Mentioned in HTML comments, like `<!-- feed: /rss.xml -->`
+//! * Mentioned in the page data embedded by Next.js in `<script id="__NEXT_DATA__">`
+//! * Referenced by the Schema.org `Blog` or `BlogPosting` metadata embedded in the HTML as JSON-LD
+//! * Generated by submitting a `<form>` in the HTML, like `<form action="/generate-rss">`
 //! * By guessing from the software used to generate the page:
 //!     * Tumblr
 //!     * WordPress
 //!     * Hugo
 //!     * Jekyll
 //!     * Ghost
+//!     * Hexo
+//!     * Pelican
+//!     * Zola
+//!     * Eleventy
+//!     * Gatsby
+//!     * Nikola
+//!     * MkDocs (with the RSS plugin)
+//!     * Discourse
+//!     * phpBB
+//!     * vBulletin
+//!     * MediaWiki
+//!     * DokuWiki
+//!     * Mastodon profiles
+//!     * Pleroma and Akkoma user profiles
+//!     * Pixelfed user profiles
+//!     * WriteFreely and write.as
+//!     * Blogger
+//!     * Bear Blog
+//!     * Hashnode
+//!     * Medium users, publications, and tags
+//!     * Nitter
+//!     * Lemmy
+//!     * DEV and other Forem communities
+//!     * Shopify blogs
+//!     * Weebly
+//!     * LiveJournal
+//!     * Dreamwidth
+//!     * Typepad
+//!     * Grav (with the feed plugin)
+//!     * Substack, Buttondown, and Mailchimp newsletters
 //! * From YouTube:
 //!     * channels
 //!     * playlists
 //!     * users
+//!     * shorts
+//! * From Reddit listings, posts, and users
+//! * From Stack Exchange questions, tags, and users
+//! * From GitHub repositories, for their releases and commits
+//! * From GitLab projects, for their releases, tags, and commits, and users, for their activity
+//! * From Gitea and Forgejo repositories and users, for their activity and commits
+//! * From third party feed generators for platforms without feeds, like npm packages via [Open RSS](https://openrss.org/)
+//! * As a last resort, a sitemap linked via the `<link rel="sitemap">` tag in the HTML
 //!
 //! ## Getting Started
 //!
@@ -64,25 +113,269 @@
 //! }
 //! ```
 
+use html5ever::serialize::{HtmlSerializer, SerializeOpts, Serializer};
+use kuchiki::iter::NodeEdge;
 use kuchiki::traits::*;
+use kuchiki::NodeData;
+use rules::{GuessAction, GuessHandler, GuessRule};
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::{HashMap, VecDeque};
 use std::fmt;
+use std::iter;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
 pub use url::Url;
 
+mod rules;
+
+// Emits a tracing debug event when the tracing feature is enabled
+macro_rules! debug {
+    ($($arg:tt)*) => {{
+        #[cfg(feature = "tracing")]
+        tracing::debug!($($arg)*);
+    }};
+}
+
 const MIGHT_BE_FEED: [&str; 4] = ["feed", "xml", "rss", "atom"];
+const FEED_ICON: [&str; 4] = ["rss", "feed-icon", "feed_icon", "feedicon"];
+// Top level Pixelfed paths that aren't profiles
+const PIXELFED_PATHS: [&str; 9] = [
+    "", "discover", "i", "login", "register", "settings", "site", "account", "timeline",
+];
+// Top level Forem paths that aren't users or organisations
+const FOREM_PATHS: [&str; 12] = [
+    "",
+    "about",
+    "dashboard",
+    "enter",
+    "latest",
+    "new",
+    "notifications",
+    "readinglist",
+    "search",
+    "settings",
+    "tags",
+    "top",
+];
+// Top level medium.com paths that aren't users or publications
+const MEDIUM_PATHS: [&str; 8] = ["", "m", "me", "p", "search", "plans", "membership", "tag"];
+const FEED_EXTENSIONS: [&str; 5] = [".rss", ".atom", ".xml", ".rdf", ".json"];
+const FEED_LINK_TEXT: [&str; 3] = ["rss", "atom", "feed"];
+// The start of headings of sections that list the feeds of a site
+const SUBSCRIBE_HEADINGS: [&str; 3] = ["subscribe", "feed", "rss"];
+// Stack Exchange sites that don't have a stackexchange.com domain
+const STACK_EXCHANGE_HOSTS: [&str; 6] = [
+    "stackoverflow.com",
+    "superuser.com",
+    "serverfault.com",
+    "askubuntu.com",
+    "mathoverflow.net",
+    "stackapps.com",
+];
+// Top level GitHub paths that aren't users or organisations
+const GITHUB_PATHS: [&str; 30] = [
+    "about",
+    "account",
+    "apps",
+    "codespaces",
+    "collections",
+    "contact",
+    "customer-stories",
+    "dashboard",
+    "enterprise",
+    "explore",
+    "features",
+    "issues",
+    "login",
+    "marketplace",
+    "new",
+    "notifications",
+    "organizations",
+    "orgs",
+    "pricing",
+    "pulls",
+    "readme",
+    "search",
+    "security",
+    "sessions",
+    "settings",
+    "site",
+    "sponsors",
+    "topics",
+    "trending",
+    "users",
+];
+// Top level GitLab paths that aren't users or groups
+const GITLAB_PATHS: [&str; 8] = [
+    "-",
+    "admin",
+    "dashboard",
+    "explore",
+    "help",
+    "search",
+    "users",
+    "projects",
+];
+// Hosts of public Gitea and Forgejo instances
+const GITEA_HOSTS: [&str; 2] = ["codeberg.org", "gitea.com"];
+// The default keywords of Gitea and Forgejo, and the links to them in their footers
+const GITEA_FINGERPRINTS: &str = "meta[name='keywords'][content^='go,git,self-hosted,'], \
+    footer a[href*='gitea.io'], footer a[href*='about.gitea.com'], footer a[href*='forgejo.org']";
+// Top level Gitea and Forgejo paths that aren't users or organisations
+const GITEA_PATHS: [&str; 10] = [
+    "-",
+    "admin",
+    "api",
+    "assets",
+    "explore",
+    "issues",
+    "notifications",
+    "org",
+    "repo",
+    "user",
+];
+// Platforms without feeds of their own that a third party generates feeds for, as the host and
+// path prefix of the pages, and the URL the page URL (without the scheme) is appended to.
+// Paths that start with @ are scoped, E.g. /package/@scope/name, so take two segments.
+const THIRD_PARTY_FEEDS: [(&str, &str, &str); 1] =
+    [("www.npmjs.com", "/package/", "https://openrss.org/")];
+// YouTube publishes updates to all of its feeds through Google's WebSub hub
+const YOUTUBE_HUB: &str = "https://pubsubhubbub.appspot.com/";
+// Discovery documents that list or link to the feeds of a site
+const WELL_KNOWN_FEEDS: [&str; 3] = [
+    "/.well-known/feeds",
+    "/.well-known/host-meta",
+    "/.well-known/host-meta.json",
+];
+// Feed paths used by versions of software before the one listed, which later changed them
+const CMS_VERSIONED_FEEDS: [(&str, CmsVersion, &str); 3] = [
+    // Ghost 3 made the trailing slash canonical
+    ("ghost", CmsVersion { major: 3, minor: 0 }, "/rss"),
+    // The /feed permalink was added in WordPress 1.5
+    (
+        "wordpress",
+        CmsVersion { major: 1, minor: 5 },
+        "/wp-rss2.php",
+    ),
+    // Feed names other than index.xml are configured with the output formats added in Hugo 0.20
+    (
+        "hugo",
+        CmsVersion {
+            major: 0,
+            minor: 20,
+        },
+        "/index.xml",
+    ),
+];
 
 #[derive(Debug, PartialEq)]
 pub enum FeedFinderError {
     Url(url::ParseError),
     Select,
+    Json(String),
+    /// The page redirects to another URL with `<meta http-equiv="refresh">`, which should be
+    /// fetched and its content used instead.
+    Redirect(Url),
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum FeedType {
     Rss,
     Atom,
     Json,
     Link,
     Guess,
+    /// A sitemap listing the pages of the site, which is not a feed but can lead to pages
+    /// that link to one.
+    Sitemap,
+    /// An OPML outline, like a blogroll or an export of subscriptions, which is not a feed
+    /// but lists them.
+    Opml,
+}
+
+/// How likely it is that a candidate is actually a feed.
+///
+/// Feeds declared by the page itself are `High`, links that merely look like feeds are
+/// `Medium`, and guesses or candidates that require fetching another document to find the
+/// real feed are `Low`. Fallback guesses that are only sometimes right are `VeryLow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Confidence {
+    VeryLow,
+    Low,
+    Medium,
+    High,
+}
+
+/// Where in the content a feed was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedSource {
+    /// The content is itself a feed.
+    Content,
+    /// A `<link rel="alternate">` tag.
+    MetaLink,
+    /// Derived from the URL of a YouTube page.
+    YouTube,
+    /// Derived from the URL of a Reddit page.
+    Reddit,
+    /// Derived from the URL of a page on a Stack Exchange site, like Stack Overflow.
+    StackExchange,
+    /// Derived from the URL of a GitHub repository.
+    GitHub,
+    /// Derived from the URL of a GitLab project or user.
+    GitLab,
+    /// Derived from the URL of a Gitea or Forgejo repository or user.
+    Gitea,
+    /// A feed generated by a third party for a platform without feeds of its own.
+    ThirdParty,
+    /// An Atom Publishing Protocol service document.
+    ServiceDocument,
+    /// An `<a>` tag in the body.
+    BodyLink,
+    /// Guessed from the software used to generate the page.
+    Guess,
+    /// A `<link rel="replies">` tag linking to a feed of comments.
+    Comments,
+    /// A `<link rel="self">` tag declaring the canonical location of the feed of a page.
+    SelfLink,
+    /// A `<link rel="sitemap">` tag.
+    Sitemap,
+    /// A URL in an HTML comment, like `<!-- feed: /rss.xml -->`.
+    HtmlComment,
+    /// A URL in the page data embedded by Next.js in `<script id="__NEXT_DATA__">`.
+    NextData,
+    /// A URL in the Schema.org `Blog` or `BlogPosting` metadata of the page, embedded in
+    /// `<script type="application/ld+json">`.
+    SchemaOrg,
+    /// A Really Simple Discovery document linked via `<link rel="EditURI">`.
+    Rsd,
+    /// A discovery document under `/.well-known/`, like `/.well-known/feeds`.
+    WellKnown,
+    /// A `<form>` tag that submits to a script that generates a feed.
+    Form,
+    /// An `<outline>` in OPML content, like a blogroll.
+    Opml,
+    /// Created by the caller with [Feed::new](struct.Feed.html#method.new) rather than found.
+    Manual,
+    /// A `<link rel="first">`, `<link rel="next">`, or `<link rel="last">` tag linking to a
+    /// page of a paged feed.
+    Paged,
+}
+
+/// What kind of site a page appears to be from.
+///
+/// Returned by [detect_site_type](fn.detect_site_type.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SiteType {
+    /// A blog, which almost always has a feed of its posts.
+    Blog,
+    /// A news site, which often has feeds for each section.
+    News,
+    /// A web app, like a progressive web app (PWA), which rarely has a feed.
+    App,
+    /// The page gave no hints about the kind of site.
+    Unknown,
 }
 
 #[derive(Debug, PartialEq)]
@@ -90,31 +383,208 @@ pub struct Feed {
     url: Url,
     type_: FeedType,
     title: Option<String>,
+    confidence: Confidence,
+    source: FeedSource,
+    hreflang: Option<String>,
+    hub: Option<Url>,
+    next: Option<Url>,
+    prev: Option<Url>,
+}
+
+/// Options for adjusting how feeds are detected.
+///
+/// Used with [detect_feeds_with_config](fn.detect_feeds_with_config.html).
+#[derive(Debug, Clone, Default)]
+pub struct FeedFinderConfig {
+    site_root: Option<String>,
+    paged_feeds: bool,
+    subscribe_widgets: bool,
+    opml_outlines: bool,
+    platform_rules: Vec<PlatformRule>,
+    scan_limit: Option<usize>,
+    max_results: Option<usize>,
+}
+
+/// A rule for guessing the feeds of a platform, added to the built-in rules with
+/// [FeedFinderConfig::with_platform_rule](struct.FeedFinderConfig.html#method.with_platform_rule).
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::{detect_feeds_with_config, FeedFinderConfig, PlatformFeed, PlatformMatch, PlatformRule};
+/// use url::Url;
+///
+/// let config = FeedFinderConfig::new().with_platform_rule(PlatformRule {
+///     name: String::from("flarum"),
+///     matches: PlatformMatch::MarkupContains(String::from("flarum-loading")),
+///     feeds: vec![PlatformFeed::RootPath(String::from("/atom"))],
+/// });
+/// let url = Url::parse("https://forum.example.com/d/1-welcome").unwrap();
+/// let html = r#"<div id="flarum-loading">Loading...</div>"#;
+/// let feeds = detect_feeds_with_config(&url, html, &config).unwrap();
+/// assert_eq!(feeds[0].url().as_str(), "https://forum.example.com/atom");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PlatformRule {
+    /// The name of the platform, used in debugging output.
+    pub name: String,
+    /// How to recognise pages from the platform.
+    pub matches: PlatformMatch,
+    /// The feeds to guess when a page matches.
+    pub feeds: Vec<PlatformFeed>,
+}
+
+/// How a [PlatformRule](struct.PlatformRule.html) recognises pages from its platform.
+///
+/// Comparisons ignore case.
+#[derive(Debug, Clone)]
+pub enum PlatformMatch {
+    /// The content of the generator meta tag starts with this, E.g. `"Hugo"`.
+    Generator(String),
+    /// The markup of the page contains this, like the name of an asset of the platform.
+    MarkupContains(String),
+    /// Any of these match.
+    Any(Vec<PlatformMatch>),
+    /// All of these match, and there is at least one.
+    All(Vec<PlatformMatch>),
+    /// The host of the page is this domain or a subdomain of it, E.g. `"example.com"` matches
+    /// `example.com` and `blog.example.com` but not `notexample.com`. A leading dot is ignored.
+    Domain(String),
+}
+
+/// A feed guessed by a [PlatformRule](struct.PlatformRule.html).
+#[derive(Debug, Clone)]
+pub enum PlatformFeed {
+    /// A path resolved against the root of the site.
+    RootPath(String),
+    /// File names guessed at each level of the path of the page, for sites that may be
+    /// hosted under a path.
+    Segments(Vec<String>),
+    /// A function of the URL of the page, which returns `None` when the page has no feed.
+    Transform(fn(&Url) -> Option<Url>),
+    /// A path resolved against the root of the site, with `{segN}` replaced by the segment
+    /// of the path of the page at index `N`, counting from zero. E.g.
+    /// `"/api/v1/{seg0}/user/{seg2}/rss"` on `/patreon/user/123` guesses
+    /// `/api/v1/patreon/user/123/rss`. Nothing is guessed if the page's path is too short.
+    UrlTemplate(String),
+}
+
+/// The outcome of running each source of feeds over some content.
+///
+/// Returned by [detect_feeds_report](fn.detect_feeds_report.html).
+#[derive(Debug)]
+pub struct FeedFinderReport {
+    sources: Vec<SourceReport>,
+    redirect: Option<Url>,
+    title: Option<String>,
+    warnings: Vec<String>,
+    elapsed: Duration,
+}
+
+/// The feeds found in some content along with the title and icon of the page.
+///
+/// Returned by [detect_site_metadata](fn.detect_site_metadata.html).
+#[derive(Debug, PartialEq)]
+pub struct SiteMetadata {
+    title: Option<String>,
+    icon: Option<Url>,
+    feeds: Vec<Feed>,
+}
+
+/// The outcome of running one source of feeds.
+#[derive(Debug)]
+pub struct SourceReport {
+    source: FeedSource,
+    candidates: Vec<Feed>,
+    error: Option<FeedFinderError>,
+    elapsed: Duration,
+}
+
+// A version of the software that generated a page, as given in its generator meta tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct CmsVersion {
+    major: u32,
+    minor: u32,
 }
 
 type FeedResult = Result<Vec<Feed>, FeedFinderError>;
+type Source<'a> = fn(&FeedFinder<'a>) -> FeedResult;
 
+// The parsed document is made up of reference counted (Rc) nodes so FeedFinder is neither Send
+// nor Sync. Wrapping it in Arc<Mutex<_>> wouldn't change that as a Mutex is only Send and Sync
+// when its contents are Send. Instead it's private and only lives for the duration of a call to
+// detect_feeds, leaving the types that are returned free to be shared between threads.
 struct FeedFinder<'a> {
     doc: kuchiki::NodeRef,
     base_url: &'a Url,
+    config: &'a FeedFinderConfig,
 }
 
 /// Find feeds in the supplied content.
 ///
 /// The `detect_feeds` function will look for feeds:
 ///
+/// * The content itself, when it is an RSS (including RSS 1.0) or Atom feed rather than HTML
+/// * The content itself, when it is an OPML outline like a blogroll, and optionally the feeds it lists
 /// * Linked via the `<link>` tag in the HTML
-/// * Linked via `<a>` tag in the HTML
+/// * Comment feeds linked via the `<link rel="replies">` tag in the HTML
+/// * The canonical feed of hub pages declared via the `<link rel="self">` tag in the HTML
+/// * Listed in an Atom Publishing Protocol service document linked from the HTML
+/// * Listed in a Really Simple Discovery document linked via `<link rel="EditURI">`
+/// * Listed in a discovery document linked from the HTML, like `/.well-known/feeds` or `/.well-known/host-meta`
+/// * Linked via `<a>` tag in the HTML, including links wrapping a feed icon
+/// * Mentioned in HTML comments, like `<!-- feed: /rss.xml -->`
+/// * Mentioned in the page data embedded by Next.js in `<script id="__NEXT_DATA__">`
+/// * Referenced by the Schema.org `Blog` or `BlogPosting` metadata embedded in the HTML as JSON-LD
+/// * Generated by submitting a `<form>` in the HTML, like `<form action="/generate-rss">`
 /// * By guessing from the software used to generate the page:
 ///     * Tumblr
 ///     * WordPress
 ///     * Hugo
 ///     * Jekyll
 ///     * Ghost
+///     * Hexo
+///     * Pelican
+///     * Zola
+///     * Eleventy
+///     * Gatsby
+///     * Nikola
+///     * MkDocs (with the RSS plugin)
+///     * Discourse
+///     * phpBB
+///     * vBulletin
+///     * MediaWiki
+///     * DokuWiki
+///     * Mastodon profiles
+///     * Pleroma and Akkoma user profiles
+///     * Pixelfed user profiles
+///     * WriteFreely and write.as
+///     * Blogger
+///     * Bear Blog
+///     * Hashnode
+///     * Medium users, publications, and tags
+///     * Nitter
+///     * Lemmy
+///     * DEV and other Forem communities
+///     * Shopify blogs
+///     * Weebly
+///     * LiveJournal
+///     * Dreamwidth
+///     * Typepad
+///     * Grav (with the feed plugin)
+///     * Substack, Buttondown, and Mailchimp newsletters
 /// * From YouTube:
 ///     * channels
 ///     * playlists
 ///     * users
+///     * shorts
+/// * From Reddit listings, posts, and users
+/// * From Stack Exchange questions, tags, and users
+/// * From GitHub repositories, for their releases and commits
+/// * From GitLab projects, for their releases, tags, and commits, and users, for their activity
+/// * From Gitea and Forgejo repositories and users, for their activity and commits
+/// * From third party feed generators for platforms without feeds, like npm packages via [Open RSS](https://openrss.org/)
+/// * As a last resort, a sitemap linked via the `<link rel="sitemap">` tag in the HTML
 ///
 /// ### Parameters
 ///
@@ -132,7 +602,15 @@ struct FeedFinder<'a> {
 /// * If they look like they are a feed (by checking for an XML or JSON MIME type).
 ///
 /// The return value is wrapped in a Result, errors can occur if a candidate URL is
-/// invalid or there is a problem parsing or traversing the HTML content.
+/// invalid or there is a problem parsing or traversing the HTML content. If the page
+/// redirects elsewhere with `<meta http-equiv="refresh">` then `FeedFinderError::Redirect`
+/// is returned with the URL to fetch and try again.
+///
+/// ### Threads
+///
+/// The HTML is parsed into a document that can't be shared between threads, so
+/// `detect_feeds` parses it on each call. It can be called from any thread and the returned
+/// feeds and errors are `Send` and `Sync`.
 ///
 /// ### Example
 ///
@@ -165,18 +643,107 @@ struct FeedFinder<'a> {
 /// }
 /// ```
 pub fn detect_feeds(base_url: &Url, html: &str) -> FeedResult {
+    detect_feeds_with_config(base_url, html, &FeedFinderConfig::default())
+}
+
+/// Find feeds in the supplied content using the supplied options.
+///
+/// This is the same as [detect_feeds](fn.detect_feeds.html) but allows the way feeds are
+/// detected to be adjusted with a [FeedFinderConfig](struct.FeedFinderConfig.html).
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::{detect_feeds_with_config, FeedFinderConfig};
+/// use url::Url;
+///
+/// let url = Url::parse("https://example.com/blog/2021/first-post/").unwrap();
+/// let html = r#"<meta name="generator" content="WordPress 5.8">"#;
+/// let config = FeedFinderConfig::new().with_site_root("/blog/");
+/// let feeds = detect_feeds_with_config(&url, html, &config).unwrap();
+/// assert_eq!(feeds[0].url().as_str(), "https://example.com/blog/feed");
+/// ```
+pub fn detect_feeds_with_config(
+    base_url: &Url,
+    html: &str,
+    config: &FeedFinderConfig,
+) -> FeedResult {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("detect_feeds", base_url = %base_url).entered();
+
+    let finder = FeedFinder {
+        doc: kuchiki::parse_html().one(html),
+        base_url,
+        config,
+    };
+
+    finder.detect()
+}
+
+/// Find feeds in the supplied content along with the title and icon of the page.
+///
+/// Apps that display the feeds found often want to show the site they came from too. This
+/// gets all of it from one parse of the content. The feeds are the same as those returned by
+/// [detect_feeds](fn.detect_feeds.html).
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::detect_site_metadata;
+/// use url::Url;
+///
+/// let url = Url::parse("https://example.com/").unwrap();
+/// let html = r#"<title>Example</title>
+/// <link rel="icon" href="/favicon.png">
+/// <link rel="alternate" href="/feed.xml" type="application/atom+xml">"#;
+/// let metadata = detect_site_metadata(&url, html).unwrap();
+/// assert_eq!(metadata.title(), Some("Example"));
+/// assert_eq!(metadata.icon().map(|icon| icon.as_str()), Some("https://example.com/favicon.png"));
+/// assert_eq!(metadata.feeds().len(), 1);
+/// ```
+pub fn detect_site_metadata(base_url: &Url, html: &str) -> Result<SiteMetadata, FeedFinderError> {
+    let finder = FeedFinder {
+        doc: kuchiki::parse_html().one(html),
+        base_url,
+        config: &FeedFinderConfig::default(),
+    };
+
+    Ok(SiteMetadata {
+        title: finder.title(),
+        icon: finder.icon()?,
+        feeds: finder.detect()?,
+    })
+}
+
+/// Find feeds in a fragment of HTML, such as a block of content from a CMS.
+///
+/// A fragment says little about the software used to generate the page it's part of, so
+/// unlike [detect_feeds](fn.detect_feeds.html) this only looks for feeds linked via `<link>`
+/// and `<a>` tags and doesn't guess.
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::detect_feeds_fragment;
+/// use url::Url;
+///
+/// let url = Url::parse("https://example.com/about/").unwrap();
+/// let html = r#"<p>Follow along via <a href="/feed">RSS</a>.</p>"#;
+/// let feeds = detect_feeds_fragment(&url, html).unwrap();
+/// assert_eq!(feeds[0].url().as_str(), "https://example.com/feed");
+/// ```
+pub fn detect_feeds_fragment(base_url: &Url, html: &str) -> FeedResult {
     let finder = FeedFinder {
         doc: kuchiki::parse_html().one(html),
         base_url,
+        config: &FeedFinderConfig::default(),
     };
 
-    let sources = [
-        FeedFinder::meta_links,
-        FeedFinder::youtube,
-        FeedFinder::body_links,
-        FeedFinder::guess,
-    ];
-    for source in &sources {
+    finder.meta_refresh()?;
+    for (_, source) in FeedFinder::sources()
+        .iter()
+        .filter(|(kind, _)| matches!(kind, FeedSource::MetaLink | FeedSource::BodyLink))
+    {
         let candidates = source(&finder)?;
         if !candidates.is_empty() {
             return Ok(candidates);
@@ -186,599 +753,6510 @@ pub fn detect_feeds(base_url: &Url, html: &str) -> FeedResult {
     Ok(Vec::new())
 }
 
-fn nth_path_segment(url: &Url, nth: usize) -> Option<&str> {
-    url.path_segments()
-        .and_then(|mut segments| segments.nth(nth))
+/// Find feeds in the supplied content as a `Stream`.
+///
+/// Detection is synchronous so this is the same as [detect_feeds](fn.detect_feeds.html),
+/// with the candidates yielded by a stream for composing with asynchronous processing of
+/// them, such as fetching each one to check that it's a feed. An error is yielded as the only
+/// item of the stream.
+///
+/// Requires the `async` feature.
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::detect_feeds_stream;
+/// use futures_util::StreamExt;
+/// use url::Url;
+///
+/// # futures_executor::block_on(async {
+/// let url = Url::parse("https://example.com/").unwrap();
+/// let html = r#"<link rel="alternate" href="/posts.rss" type="application/rss+xml" />"#;
+/// let mut feeds = detect_feeds_stream(&url, html);
+/// while let Some(feed) = feeds.next().await {
+///     println!("{:?}", feed);
+/// }
+/// # });
+/// ```
+#[cfg(feature = "async")]
+pub fn detect_feeds_stream(
+    base_url: &Url,
+    html: &str,
+) -> impl futures_util::Stream<Item = Result<Feed, FeedFinderError>> {
+    let items = match detect_feeds(base_url, html) {
+        Ok(feeds) => feeds.into_iter().map(Ok).collect(),
+        Err(err) => vec![Err(err)],
+    };
+    futures_util::stream::iter(items)
 }
 
-impl<'a> FeedFinder<'a> {
-    fn meta_links(&self) -> FeedResult {
-        let mut feeds = vec![];
-        for link in self
-            .doc
-            .select("link[rel='alternate']")
-            .map_err(|_| FeedFinderError::Select)?
-        {
-            let attrs = link.attributes.borrow();
-            let title = attrs.get("title").map(|title| title.to_owned());
-            match (attrs.get("type"), attrs.get("href")) {
-                (Some("application/rss+xml"), Some(href)) => feeds.push(Feed {
-                    url: self.base_url.join(href).map_err(FeedFinderError::Url)?,
-                    type_: FeedType::Rss,
-                    title,
-                }),
-                (Some("application/atom+xml"), Some(href)) => feeds.push(Feed {
-                    url: self.base_url.join(href).map_err(FeedFinderError::Url)?,
-                    type_: FeedType::Atom,
-                    title,
-                }),
-                (Some("application/json"), Some(href)) => feeds.push(Feed {
-                    url: self.base_url.join(href).map_err(FeedFinderError::Url)?,
-                    type_: FeedType::Json,
-                    title,
-                }),
-                _ => (),
+/// Find feeds in the supplied content and report how each source contributed.
+///
+/// This is intended for working out why the expected feeds were, or were not, found for a
+/// page. [detect_feeds](fn.detect_feeds.html) stops at the first source that finds any
+/// candidates and returns the first error encountered. In contrast `detect_feeds_report` runs
+/// every source, recording the candidates, error, and time taken for each one. Problems the
+/// HTML parser recovered from, like misnested elements, are recorded as warnings.
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::detect_feeds_report;
+/// use url::Url;
+///
+/// let url = Url::parse("https://example.com/").unwrap();
+/// let html = r#"<a href="/feed.xml">RSS</a>"#;
+/// let report = detect_feeds_report(&url, html);
+/// for source in report.sources() {
+///     println!(
+///         "{:?}: {} candidates in {:?}",
+///         source.source(),
+///         source.candidates().len(),
+///         source.elapsed()
+///     );
+/// }
+/// assert_eq!(report.feeds().len(), 1);
+/// ```
+pub fn detect_feeds_report(base_url: &Url, html: &str) -> FeedFinderReport {
+    let start = Instant::now();
+    let warnings = Rc::new(RefCell::new(Vec::new()));
+    let opts = kuchiki::ParseOpts {
+        on_parse_error: Some(Box::new({
+            let warnings = Rc::clone(&warnings);
+            move |message: Cow<'static, str>| warnings.borrow_mut().push(message.into_owned())
+        })),
+        ..Default::default()
+    };
+    let finder = FeedFinder {
+        doc: kuchiki::parse_html_with_options(opts).one(html),
+        base_url,
+        config: &FeedFinderConfig::default(),
+    };
+
+    let redirect = match finder.meta_refresh() {
+        Err(FeedFinderError::Redirect(url)) => Some(url),
+        _ => None,
+    };
+    let sources = FeedFinder::sources()
+        .iter()
+        .map(|(source, detect)| {
+            let source_start = Instant::now();
+            let (candidates, error) = match detect(&finder) {
+                Ok(candidates) => (candidates, None),
+                Err(err) => (Vec::new(), Some(err)),
+            };
+            SourceReport {
+                source: *source,
+                candidates,
+                error,
+                elapsed: source_start.elapsed(),
             }
-        }
+        })
+        .collect();
 
-        Ok(feeds)
+    let warnings = warnings.replace(Vec::new());
+    FeedFinderReport {
+        sources,
+        redirect,
+        title: finder.title(),
+        warnings,
+        elapsed: start.elapsed(),
     }
+}
 
-    fn youtube(&self) -> FeedResult {
-        let mut feeds = vec![];
-        let url = self.base_url.as_str();
-
-        if url.starts_with("https://www.youtube.com/channel/") {
-            // Get the path segment after /channel/
-            if let Some(id) = nth_path_segment(self.base_url, 1) {
-                let feed = Url::parse(&format!(
-                    "https://www.youtube.com/feeds/videos.xml?channel_id={}",
-                    id
-                ))
-                .map_err(FeedFinderError::Url)?;
-                feeds.push(Feed {
-                    url: feed,
-                    type_: FeedType::Atom,
-                    title: None,
-                });
-            }
-        } else if url.starts_with("https://www.youtube.com/user/") {
-            // Get the path segment after /user/
-            if let Some(id) = nth_path_segment(self.base_url, 1) {
-                let feed = Url::parse(&format!(
-                    "https://www.youtube.com/feeds/videos.xml?user={}",
-                    id
-                ))
-                .map_err(FeedFinderError::Url)?;
-                feeds.push(Feed {
-                    url: feed,
-                    type_: FeedType::Atom,
-                    title: None,
-                });
-            }
-        } else if url.starts_with("https://www.youtube.com/playlist?list=")
-            || url.starts_with("https://www.youtube.com/watch")
-        {
-            // get the value of the list query param
-            for (key, value) in self.base_url.query_pairs() {
-                if key == "list" {
-                    let feed = Url::parse(&format!(
-                        "https://www.youtube.com/feeds/videos.xml?playlist_id={}",
-                        value
-                    ))
-                    .map_err(FeedFinderError::Url)?;
-                    feeds.push(Feed {
-                        url: feed,
-                        type_: FeedType::Atom,
-                        title: None,
-                    });
-                    break;
-                }
+/// Find feeds for the author and provider of an oEmbed response.
+///
+/// Some platforms don't link to feeds from their pages, but do advertise an oEmbed endpoint via
+/// a `<link type="application/json+oembed">` tag. The JSON response from that endpoint
+/// includes the `author_url` and `provider_url`, which can lead to a feed even without the
+/// HTML of those pages. For example, the oEmbed response for a YouTube video gives the channel
+/// of the author, from which the channel feed can be found.
+///
+/// `base_url` is the URL of the oEmbed response, which relative URLs are resolved against.
+/// As with [detect_feeds](fn.detect_feeds.html) no requests are made.
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::detect_feeds_from_oembed;
+/// use url::Url;
+///
+/// let url = Url::parse("https://www.youtube.com/oembed?url=https://www.youtube.com/watch?v=0gjFYpvHyrY").unwrap();
+/// let json = r#"{
+///     "type": "video",
+///     "author_url": "https://www.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA",
+///     "provider_url": "https://www.youtube.com/"
+/// }"#;
+/// let feeds = detect_feeds_from_oembed(&url, json).unwrap();
+/// assert_eq!(
+///     feeds[0].url().as_str(),
+///     "https://www.youtube.com/feeds/videos.xml?channel_id=UCaYhcUwRBNscFNUKTjgPFiA"
+/// );
+/// ```
+pub fn detect_feeds_from_oembed(base_url: &Url, oembed_json: &str) -> FeedResult {
+    let oembed: serde_json::Value =
+        serde_json::from_str(oembed_json).map_err(|err| FeedFinderError::Json(err.to_string()))?;
+
+    let mut feeds: Vec<Feed> = Vec::new();
+    for key in &["author_url", "provider_url"] {
+        let url = match oembed.get(key).and_then(|url| url.as_str()) {
+            Some(url) => base_url.join(url).map_err(FeedFinderError::Url)?,
+            None => continue,
+        };
+        for feed in detect_feeds(&url, "")? {
+            if !feeds.iter().any(|existing| existing.url == feed.url) {
+                feeds.push(feed);
             }
         }
+    }
 
-        Ok(feeds)
+    Ok(feeds)
+}
+
+/// Find feeds in the supplied content, grouped by their type.
+///
+/// This runs the same detection as [detect_feeds](fn.detect_feeds.html) and then partitions
+/// the results by [FeedType](enum.FeedType.html). The feeds of each type are in the order they
+/// were detected. Types that had no feeds are not present in the map.
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::{detect_feeds_grouped, FeedType};
+/// use url::Url;
+///
+/// let url = Url::parse("https://example.com/").unwrap();
+/// let html = r#"
+///     <link rel="alternate" href="/feed.rss" type="application/rss+xml">
+///     <link rel="alternate" href="/feed.json" type="application/json">"#;
+/// let feeds = detect_feeds_grouped(&url, html).unwrap();
+/// assert_eq!(feeds[&FeedType::Rss].len(), 1);
+/// assert_eq!(feeds[&FeedType::Json].len(), 1);
+/// ```
+pub fn detect_feeds_grouped(
+    base_url: &Url,
+    html: &str,
+) -> Result<HashMap<FeedType, Vec<Feed>>, FeedFinderError> {
+    let mut groups: HashMap<FeedType, Vec<Feed>> = HashMap::new();
+    for feed in detect_feeds(base_url, html)? {
+        groups.entry(feed.type_).or_default().push(feed);
     }
 
-    // Searches the body for links to things that might be feeds
-    fn body_links(&self) -> FeedResult {
-        let mut feeds = vec![];
+    Ok(groups)
+}
 
-        for a in self.doc.select("a").map_err(|_| FeedFinderError::Select)? {
-            let attrs = a.attributes.borrow();
-            if let Some(href) = attrs.get("href") {
-                if MIGHT_BE_FEED.iter().any(|hint| href.contains(hint)) {
-                    feeds.push(Feed {
-                        url: self.base_url.join(href).map_err(FeedFinderError::Url)?,
-                        type_: FeedType::Link,
-                        title: None,
-                    })
-                }
-            }
-        }
+/// Keep only the feeds of the supplied types.
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::{detect_feeds, filter_by_type, FeedType};
+/// use url::Url;
+///
+/// let url = Url::parse("https://example.com/").unwrap();
+/// let html = r#"<link rel="alternate" href="/feed.json" type="application/json">"#;
+/// let feeds = detect_feeds(&url, html).unwrap();
+/// let json_feeds = filter_by_type(feeds, &[FeedType::Json]);
+/// assert_eq!(json_feeds.len(), 1);
+/// ```
+pub fn filter_by_type(feeds: Vec<Feed>, types: &[FeedType]) -> Vec<Feed> {
+    feeds
+        .into_iter()
+        .filter(|feed| types.contains(&feed.type_))
+        .collect()
+}
 
-        Ok(feeds)
-    }
+/// Guess what kind of site the supplied content is from.
+///
+/// This is only a hint, useful for deciding how hard to look for feeds, such as whether to
+/// fetch guessed candidates when none were found in the page.
+///
+/// ### Example
+///
+/// ```rust
+/// use feedfinder::{detect_site_type, SiteType};
+/// use url::Url;
+///
+/// let url = Url::parse("https://example.com/").unwrap();
+/// let html = r#"<link rel="manifest" href="/manifest.json">
+/// <link rel="apple-touch-icon" href="/icon.png">"#;
+/// assert_eq!(detect_site_type(&url, html), SiteType::App);
+/// ```
+pub fn detect_site_type(base_url: &Url, html: &str) -> SiteType {
+    let finder = FeedFinder {
+        doc: kuchiki::parse_html().one(html),
+        base_url,
+        config: &FeedFinderConfig::default(),
+    };
 
-    // Well this sure isn't pretty. TODO: Clean up
-    fn guess_segments(&self, feed_file: &str) -> FeedResult {
-        let mut feeds = Vec::new();
+    finder.site_type()
+}
 
-        if let Some(segments) = self.base_url.path_segments() {
-            let mut remaining_segments = segments.collect::<Vec<_>>();
-            let mut segments = vec!["", feed_file];
+// Checks for an image that looks like the feed icon, which is often used as the content of a
+// link to a feed with a URL that gives no hint that it's a feed.
+fn has_feed_icon(node: &kuchiki::NodeRef) -> bool {
+    let images = match node.select("img, svg") {
+        Ok(images) => images,
+        Err(()) => return false,
+    };
+    images.into_iter().any(|image| {
+        let attrs = image.attributes.borrow();
+        let hints = match &*image.name.local {
+            "img" => [attrs.get("src"), attrs.get("alt")],
+            _ => [attrs.get("class"), attrs.get("aria-label")],
+        };
+        hints.iter().flatten().any(|hint| {
+            let hint = hint.to_lowercase();
+            FEED_ICON.iter().any(|icon| hint.contains(icon))
+        })
+    })
+}
 
-            loop {
-                let url = self
-                    .base_url
-                    .join(&segments.join("/"))
-                    .map_err(FeedFinderError::Url)?;
-                feeds.push(Feed {
-                    url,
-                    type_: FeedType::Guess,
-                    title: None,
-                });
+// Checks if a link is in a section headed like a list of feeds, either by a heading before
+// it, as in <h3>Subscribe</h3><ul><li><a>...</a></li></ul>, or by the first heading within it.
+// Only the closest few containers are checked so that a heading elsewhere on the page doesn't
+// count.
+fn in_subscribe_widget(node: &kuchiki::NodeRef) -> bool {
+    let is_heading = |node: &kuchiki::NodeRef| {
+        node.as_element()
+            .map(|element| {
+                matches!(
+                    &*element.name.local,
+                    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "header" | "strong" | "p"
+                )
+            })
+            .unwrap_or(false)
+    };
+    let is_subscribe = |heading: kuchiki::NodeRef| {
+        let text = heading.text_contents().trim().to_lowercase();
+        text.len() < 40
+            && SUBSCRIBE_HEADINGS
+                .iter()
+                .any(|start| text.starts_with(start))
+    };
 
-                if remaining_segments.is_empty() {
-                    break;
-                }
+    node.ancestors().take(3).any(|container| {
+        let is_container = container
+            .as_element()
+            .map(|element| {
+                matches!(
+                    &*element.name.local,
+                    "ul" | "ol" | "div" | "section" | "aside" | "nav" | "footer"
+                )
+            })
+            .unwrap_or(false);
+        is_container
+            && (container
+                .preceding_siblings()
+                .find(|sibling| sibling.as_element().is_some())
+                .filter(is_heading)
+                .map(is_subscribe)
+                .unwrap_or(false)
+                || container
+                    .children()
+                    .find(is_heading)
+                    .map(is_subscribe)
+                    .unwrap_or(false))
+    })
+}
 
-                let index = segments.len() - 1;
-                let segment = remaining_segments.remove(0);
-                if segment.is_empty() {
-                    // Skip empty strings, which should only occur as the last element
-                    break;
-                }
+// The lowercased tokens of the rel attribute of an element
+fn rel_tokens(attrs: &kuchiki::Attributes) -> Vec<String> {
+    attrs
+        .get("rel")
+        .map(|rel| rel.split_whitespace().map(str::to_lowercase).collect())
+        .unwrap_or_default()
+}
 
-                segments.insert(index, segment);
+// Strips any parameters, like charset, from a MIME type and normalises it for comparison
+fn media_type(type_: &str) -> String {
+    type_
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_lowercase()
+}
+
+fn nth_path_segment(url: &Url, nth: usize) -> Option<&str> {
+    url.path_segments()
+        .and_then(|mut segments| segments.nth(nth))
+}
+
+// Whether the host is the domain or a subdomain of it. A leading dot on the domain is ignored,
+// so ".example.com" and "example.com" both match example.com and blog.example.com, but not
+// notexample.com.
+fn on_domain(host: &str, domain: &str) -> bool {
+    let domain = domain.trim_start_matches('.');
+    host == domain
+        || (host.len() > domain.len()
+            && host.ends_with(domain)
+            && host[..host.len() - domain.len()].ends_with('.'))
+}
+
+// Collects the URLs in a Schema.org property, which may be a URL, an object with a URL, like an
+// EntryPoint or DataFeed, or a list of them
+fn schema_org_urls<'v>(value: &'v serde_json::Value, urls: &mut Vec<&'v str>) {
+    match value {
+        serde_json::Value::String(url) => urls.push(url),
+        serde_json::Value::Array(items) => {
+            for item in items {
+                schema_org_urls(item, urls);
+            }
+        }
+        serde_json::Value::Object(fields) => {
+            for key in &["url", "@id", "target", "urlTemplate"] {
+                if let Some(value) = fields.get(*key) {
+                    schema_org_urls(value, urls);
+                }
             }
         }
+        _ => {}
+    }
+}
 
-        Ok(feeds)
+// Replaces each {segN} in the template with the segment of the path of the URL at index N
+fn fill_template(template: &str, url: &Url) -> Option<String> {
+    let mut filled = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("{seg") {
+        filled.push_str(&rest[..start]);
+        let tail = &rest[start + 4..];
+        let end = tail.find('}')?;
+        let segment =
+            nth_path_segment(url, tail[..end].parse().ok()?).filter(|seg| !seg.is_empty())?;
+        filled.push_str(segment);
+        rest = &tail[end + 1..];
     }
+    filled.push_str(rest);
 
-    // Guesses the feed for some well known locations
-    // Tumblr
-    // Wordpress
-    // Ghost
-    // Jekyll
-    // Hugo
-    fn guess(&self) -> FeedResult {
-        let markup = self.doc.to_string().to_lowercase();
-
-        let url = if markup.contains("tumblr.com") {
-            Some(self.base_url.join("/rss").map_err(FeedFinderError::Url)?)
-        } else if markup.contains("wordpress") {
-            Some(self.base_url.join("/feed").map_err(FeedFinderError::Url)?)
-        } else if markup.contains("hugo") {
-            return self.guess_segments("index.xml");
-        } else if markup.contains("jekyll")
-            || self
-                .base_url
-                .host_str()
-                .map(|host| host.ends_with("github.io"))
-                .unwrap_or(false)
-        {
-            return self.guess_segments("atom.xml");
-        } else if markup.contains("ghost") {
-            Some(self.base_url.join("/rss/").map_err(FeedFinderError::Url)?)
-        } else {
-            None
-        };
+    Some(filled)
+}
 
-        Ok(url
-            .map(|url| {
-                vec![Feed {
-                    url,
-                    type_: FeedType::Guess,
-                    title: None,
-                }]
-            })
-            .unwrap_or_else(Vec::new))
+impl CmsVersion {
+    // Parses the version from generator content like "Ghost 5.21" or "WordPress 6.4.2", where
+    // name is the lowercase name of the software.
+    fn parse(generator: &str, name: &str) -> Option<CmsVersion> {
+        let generator = generator.trim().to_lowercase();
+        let version = generator
+            .strip_prefix(name)?
+            .trim_start()
+            .trim_start_matches('v')
+            .split(|c: char| !c.is_ascii_digit() && c != '.')
+            .next()?;
+        let mut parts = version.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts
+            .next()
+            .and_then(|minor| minor.parse().ok())
+            .unwrap_or(0);
+
+        Some(CmsVersion { major, minor })
     }
 }
 
-impl Feed {
+impl<'a> FeedFinder<'a> {
+    // The sources of feeds in the order they're tried
+    fn sources() -> [(FeedSource, Source<'a>); 20] {
+        [
+            (FeedSource::Content, FeedFinder::self_feed),
+            (FeedSource::MetaLink, FeedFinder::meta_links),
+            (FeedSource::Paged, FeedFinder::paged_links),
+            (FeedSource::YouTube, FeedFinder::youtube),
+            (FeedSource::Reddit, FeedFinder::reddit),
+            (FeedSource::StackExchange, FeedFinder::stack_exchange),
+            (FeedSource::GitHub, FeedFinder::github),
+            (FeedSource::GitLab, FeedFinder::gitlab),
+            (FeedSource::Gitea, FeedFinder::gitea),
+            (FeedSource::ThirdParty, FeedFinder::third_party),
+            (
+                FeedSource::ServiceDocument,
+                FeedFinder::atom_service_document,
+            ),
+            (FeedSource::BodyLink, FeedFinder::body_links),
+            (FeedSource::HtmlComment, FeedFinder::comment_links),
+            (FeedSource::NextData, FeedFinder::next_data),
+            (FeedSource::SchemaOrg, FeedFinder::schema_org),
+            (FeedSource::Form, FeedFinder::body_form_links),
+            (FeedSource::Guess, FeedFinder::guess),
+            (FeedSource::Rsd, FeedFinder::rsd_hint),
+            (FeedSource::WellKnown, FeedFinder::well_known_links),
+            (FeedSource::Sitemap, FeedFinder::link_sitemap),
+        ]
+    }
+
+    // Tries each source in turn, returning the candidates of the first to find any
+    #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+    fn detect(&self) -> FeedResult {
+        self.meta_refresh()?;
+        for (kind, source) in FeedFinder::sources().iter() {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!("source", source = ?kind).entered();
+
+            let mut candidates = source(self)?;
+            debug!(candidates = candidates.len(), "source finished");
+            if !candidates.is_empty() {
+                candidates.truncate(self.config.max_results.unwrap_or(usize::MAX));
+                return Ok(candidates);
+            }
+        }
+
+        debug!("no feeds found");
+        Ok(Vec::new())
+    }
+
+    // The title of the page
+    fn title(&self) -> Option<String> {
+        self.doc
+            .select_first("title")
+            .ok()
+            .map(|title| title.text_contents().trim().to_owned())
+            .filter(|title| !title.is_empty())
+    }
+
+    // The icon of the site, preferring a favicon (rel="icon" or rel="shortcut icon") to the
+    // larger icons for the home screen of iOS
+    fn icon(&self) -> Result<Option<Url>, FeedFinderError> {
+        let link = [
+            "link[rel~='icon'][href]",
+            "link[rel~='apple-touch-icon'][href]",
+        ]
+        .iter()
+        .find_map(|selector| self.doc.select_first(selector).ok());
+        match link {
+            Some(link) => self
+                .join(link.attributes.borrow().get("href").unwrap_or_default())
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    // Pages that redirect with a refresh have nothing of interest, so tell the caller where to
+    // go instead. Refreshes without a URL or back to the page itself just reload it.
+    fn meta_refresh(&self) -> Result<(), FeedFinderError> {
+        let metas = self
+            .doc
+            .select("meta[http-equiv]")
+            .map_err(|_| FeedFinderError::Select)?;
+        for meta in metas {
+            let attrs = meta.attributes.borrow();
+            if !attrs
+                .get("http-equiv")
+                .map(|equiv| equiv.eq_ignore_ascii_case("refresh"))
+                .unwrap_or(false)
+            {
+                continue;
+            }
+
+            // The content is the delay, optionally followed by the URL. E.g. "0; url=/new/"
+            let target = attrs.get("content").and_then(|content| {
+                content.find(&[';', ','][..]).map(|end| {
+                    let url = content[end + 1..].trim();
+                    let url = match url.get(..4) {
+                        Some(prefix) if prefix.eq_ignore_ascii_case("url=") => &url[4..],
+                        _ => url,
+                    };
+                    url.trim().trim_matches(&['\'', '"'][..])
+                })
+            });
+            if let Some(target) = target.filter(|target| !target.is_empty()) {
+                let url = self.join(target)?;
+                if !self.is_base_url(&url) {
+                    debug!(%url, "page redirects");
+                    return Err(FeedFinderError::Redirect(url));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Handles being given the content of a feed instead of a HTML page, which happens when
+    // the page URL redirects to a feed. The HTML parser places the root element of the feed
+    // in the body so look for that. Processing instructions, like the <?xml-stylesheet?> of
+    // feeds styled for browsers, are parsed as comments so don't get in the way.
+    fn self_feed(&self) -> FeedResult {
+        let root = match self
+            .doc
+            .select_first("body")
+            .ok()
+            .and_then(|body| body.as_node().children().elements().next())
+        {
+            Some(root) => root,
+            None => return Ok(Vec::new()),
+        };
+
+        let (type_, title_selector) = match &*root.name.local {
+            "rss" => (FeedType::Rss, "channel > title"),
+            "feed" => (FeedType::Atom, "feed > title"),
+            // RSS 1.0, which the parser names in lowercase
+            "rdf:rdf" => (FeedType::Rss, "channel > title"),
+            // The parser drops the <head> of OPML, leaving its <title> in <opml>
+            "opml" => (FeedType::Opml, "opml > title"),
+            _ => return Ok(Vec::new()),
+        };
+        debug!(root = %root.name.local, "content is a feed");
+
+        // Feeds can declare their own URL via a self link. In Atom feeds this is a plain
+        // <link rel="self">, RSS feeds borrow it from Atom as <atom:link rel="self">. The
+        // RSS <channel><link> is the URL of the website, not the feed, so it's not used.
+        let self_link = root.as_node().descendants().elements().find_map(|el| {
+            let attrs = el.attributes.borrow();
+            match &*el.name.local {
+                "link" | "atom:link" if attrs.get("rel") == Some("self") => {
+                    attrs.get("href").map(|href| href.to_owned())
+                }
+                _ => None,
+            }
+        });
+        let url = match self_link {
+            Some(href) => self.join(&href)?,
+            None => self.base_url.clone(),
+        };
+        let title = root
+            .as_node()
+            .select_first(title_selector)
+            .ok()
+            .map(|title| title.text_contents().trim().to_owned())
+            .filter(|title| !title.is_empty());
+
+        let mut feeds = vec![Feed {
+            title,
+            ..Feed::candidate(url, type_, Confidence::High, FeedSource::Content)
+        }];
+        if type_ == FeedType::Opml && self.config.opml_outlines {
+            feeds.extend(self.opml_outlines(root.as_node())?);
+        }
+
+        Ok(feeds)
+    }
+
+    // The feeds listed in OPML. Outlines are self-closing, which the HTML parser ignores so
+    // they end up nested in each other, but as long as all of them are found that's fine.
+    // Attribute names are lowercased by the parser too, E.g. xmlUrl becomes xmlurl.
+    fn opml_outlines(&self, opml: &kuchiki::NodeRef) -> FeedResult {
+        let mut feeds: Vec<Feed> = Vec::new();
+        for outline in opml
+            .select("outline[xmlurl]")
+            .map_err(|_| FeedFinderError::Select)?
+        {
+            let attrs = outline.attributes.borrow();
+            let url = self.join(attrs.get("xmlurl").unwrap_or_default())?;
+            if feeds.iter().any(|feed| feed.url == url) {
+                continue;
+            }
+            let type_ = match attrs.get("type").map(str::to_lowercase).as_deref() {
+                Some("rss") => FeedType::Rss,
+                Some("atom") => FeedType::Atom,
+                _ => FeedType::Link,
+            };
+            feeds.push(Feed {
+                title: attrs
+                    .get("title")
+                    .or_else(|| attrs.get("text"))
+                    .map(|title| title.to_owned()),
+                ..Feed::candidate(url, type_, Confidence::Medium, FeedSource::Opml)
+            });
+        }
+
+        Ok(feeds)
+    }
+
+    fn meta_links(&self) -> FeedResult {
+        let mut feeds = vec![];
+        let mut links: Vec<kuchiki::NodeDataRef<kuchiki::ElementData>> = vec![];
+        let mut pages: Vec<kuchiki::NodeDataRef<kuchiki::ElementData>> = vec![];
+        for element in self.all_elements() {
+            if &*element.name.local == "link"
+                && rel_tokens(&element.attributes.borrow())
+                    .iter()
+                    .any(|rel| matches!(rel.as_str(), "next" | "prev" | "previous"))
+            {
+                pages.push(element);
+                continue;
+            }
+            // rel is a list of tokens, E.g. rel="alternate feed", but each link is one
+            // candidate however many of them match
+            let matches = &*element.name.local == "link"
+                && rel_tokens(&element.attributes.borrow())
+                    .iter()
+                    .any(|rel| matches!(rel.as_str(), "alternate" | "feed" | "replies" | "self"));
+            if matches && !links.iter().any(|link| link.as_node() == element.as_node()) {
+                links.push(element);
+            }
+        }
+
+        for link in links {
+            let attrs = link.attributes.borrow();
+            let title = attrs.get("title").map(|title| title.to_owned());
+            let hreflang = attrs.get("hreflang").map(|hreflang| hreflang.to_owned());
+            // The Atom threading extensions (RFC 4685) link to comment feeds with rel="replies"
+            let rels = rel_tokens(&attrs);
+            let source = if rels.iter().any(|rel| rel == "replies") {
+                FeedSource::Comments
+            } else if rels.iter().any(|rel| rel == "self") {
+                FeedSource::SelfLink
+            } else {
+                FeedSource::MetaLink
+            };
+            if let (Some(type_), Some(href)) = (
+                attrs.get("type").and_then(FeedType::from_mime),
+                attrs.get("href"),
+            ) {
+                feeds.push(Feed {
+                    title,
+                    hreflang,
+                    ..Feed::candidate(self.join(href)?, type_, Confidence::High, source)
+                });
+            } else {
+                debug!(
+                    type_ = ?attrs.get("type"),
+                    "skipping link that isn't to a known feed type"
+                );
+            }
+        }
+
+        // Hub pages declare the canonical location of their feed with rel="self", which is
+        // preferred over any alternates
+        feeds.sort_by_key(|feed| feed.source != FeedSource::SelfLink);
+
+        // Typed next and previous links are the pages of the feed (RFC 5005), which belong to
+        // the first feed of the same type. Untyped ones are the pages of the HTML.
+        for page in pages {
+            let attrs = page.attributes.borrow();
+            let type_ = match attrs.get("type").and_then(FeedType::from_mime) {
+                Some(type_) => type_,
+                None => continue,
+            };
+            let href = match attrs.get("href") {
+                Some(href) => href,
+                None => continue,
+            };
+            let feed = feeds
+                .iter_mut()
+                .find(|feed| feed.type_ == type_ && feed.source != FeedSource::Comments);
+            if let Some(feed) = feed {
+                let url = Some(self.join(href)?);
+                if rel_tokens(&attrs).iter().any(|rel| rel == "next") {
+                    feed.next = feed.next.take().or(url);
+                } else {
+                    feed.prev = feed.prev.take().or(url);
+                }
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Links to the pages of a paged feed (RFC 5005), which are only used if enabled
+    fn paged_links(&self) -> FeedResult {
+        if !self.config.paged_feeds {
+            return Ok(Vec::new());
+        }
+
+        let links = self
+            .doc
+            .select("link[rel]")
+            .map_err(|_| FeedFinderError::Select)?;
+        let mut feeds = Vec::new();
+        for link in links {
+            let attrs = link.attributes.borrow();
+            if !rel_tokens(&attrs)
+                .iter()
+                .any(|rel| matches!(rel.as_str(), "first" | "next" | "last"))
+            {
+                continue;
+            }
+            // Untyped links are how HTML pages link to the next page of HTML
+            let type_ = match attrs.get("type").and_then(FeedType::from_mime) {
+                Some(type_) => type_,
+                None => continue,
+            };
+            if let Some(href) = attrs.get("href") {
+                feeds.push(Feed {
+                    title: attrs.get("title").map(|title| title.to_owned()),
+                    ..Feed::candidate(
+                        self.join(href)?,
+                        type_,
+                        Confidence::Medium,
+                        FeedSource::Paged,
+                    )
+                });
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    fn youtube(&self) -> FeedResult {
+        match self.base_url.host_str() {
+            Some("www.youtube.com")
+            | Some("youtube.com")
+            | Some("m.youtube.com")
+            | Some("www.youtube-nocookie.com") => {}
+            _ => return Ok(Vec::new()),
+        }
+
+        let id = nth_path_segment(self.base_url, 1).filter(|id| !id.is_empty());
+        let query = match (nth_path_segment(self.base_url, 0), id) {
+            // Any tab of the channel, like /videos or /community, gets the channel feed.
+            // TODO: The Community tab has no feed of its own and its posts aren't reliably
+            // included in the channel feed. Revisit if YouTube adds one.
+            (Some("channel"), Some(id)) => Some(("channel_id", id.to_owned())),
+            // Shorts don't link to the channel feed but the page includes the channel id
+            (Some("shorts"), _) => self.youtube_channel_id().map(|id| ("channel_id", id)),
+            (Some("user"), Some(id)) => Some(("user", id.to_owned())),
+            (Some("playlist"), _) | (Some("watch"), _) => self
+                .base_url
+                .query_pairs()
+                .find(|(key, _)| key == "list")
+                .map(|(_, list)| ("playlist_id", list.into_owned())),
+            _ => None,
+        };
+
+        match query {
+            Some((key, value)) => {
+                let url = Url::parse(&format!(
+                    "https://www.youtube.com/feeds/videos.xml?{}={}",
+                    key, value
+                ))
+                .map_err(FeedFinderError::Url)?;
+                Ok(vec![Feed {
+                    hub: Url::parse(YOUTUBE_HUB).ok(),
+                    ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+                }])
+            }
+            None => Ok(Vec::new()),
+        }
+    }
+
+    // Reddit has a feed of every listing, post, and user at .rss under its path, keeping the
+    // query for the sort order. Despite the name they're Atom feeds.
+    fn reddit(&self) -> FeedResult {
+        match self.base_url.host_str() {
+            Some(host) if host == "reddit.com" || host.ends_with(".reddit.com") => {}
+            _ => return Ok(Vec::new()),
+        }
+
+        let mut url = self.base_url.clone();
+        url.set_path(&format!(
+            "{}/.rss",
+            self.base_url.path().trim_end_matches('/')
+        ));
+        url.set_fragment(None);
+        Ok(vec![Feed::candidate(
+            url,
+            FeedType::Atom,
+            Confidence::High,
+            FeedSource::Reddit,
+        )])
+    }
+
+    // Stack Exchange sites have Atom feeds of the answers to each question, and of the
+    // questions with a tag, by a user, or on the whole site
+    fn stack_exchange(&self) -> FeedResult {
+        let host = match self.base_url.host_str() {
+            Some(host) => host.trim_start_matches("www."),
+            None => return Ok(Vec::new()),
+        };
+        let is_stack_exchange = host.ends_with(".stackexchange.com")
+            || STACK_EXCHANGE_HOSTS
+                .iter()
+                .any(|site| host == *site || host.ends_with(&format!(".{}", site)));
+        if !is_stack_exchange {
+            return Ok(Vec::new());
+        }
+
+        let segment = |nth| nth_path_segment(self.base_url, nth).filter(|s| !s.is_empty());
+        let is_id = |id: &&str| id.bytes().all(|byte| byte.is_ascii_digit());
+        let path = match (segment(0), segment(1), segment(2)) {
+            (Some("questions"), Some("tagged"), Some(tag)) | (Some("tags"), Some(tag), _) => {
+                format!("/feeds/tag/{}", tag)
+            }
+            (Some("questions"), Some(id), _) | (Some("q"), Some(id), _) if is_id(&id) => {
+                format!("/feeds/question/{}", id)
+            }
+            (Some("users"), Some(id), _) if is_id(&id) => format!("/feeds/user/{}", id),
+            (None, _, _) | (Some("questions"), None, _) => String::from("/feeds"),
+            _ => return Ok(Vec::new()),
+        };
+
+        Ok(vec![Feed::candidate(
+            self.base_url.join(&path).map_err(FeedFinderError::Url)?,
+            FeedType::Atom,
+            Confidence::High,
+            FeedSource::StackExchange,
+        )])
+    }
+
+    // GitHub repositories have Atom feeds of their releases and commits. Any page of the
+    // repository, like an issue or an Actions run, gets the feeds of the repository. Clone
+    // URLs, like ssh://git@github.com/owner/repo.git, work too.
+    fn github(&self) -> FeedResult {
+        if self.base_url.host_str() != Some("github.com") {
+            return Ok(Vec::new());
+        }
+
+        let repository = match (
+            nth_path_segment(self.base_url, 0),
+            nth_path_segment(self.base_url, 1),
+        ) {
+            (Some(owner), Some(repo))
+                if !owner.is_empty() && !repo.is_empty() && !GITHUB_PATHS.contains(&owner) =>
+            {
+                format!("{}/{}", owner, repo.trim_end_matches(".git"))
+            }
+            _ => return Ok(Vec::new()),
+        };
+
+        ["releases", "commits"]
+            .iter()
+            .map(|feed| {
+                let url = format!("https://github.com/{}/{}.atom", repository, feed);
+                Ok(Feed::candidate(
+                    Url::parse(&url).map_err(FeedFinderError::Url)?,
+                    FeedType::Atom,
+                    Confidence::High,
+                    FeedSource::GitHub,
+                ))
+            })
+            .collect()
+    }
+
+    // GitLab projects have Atom feeds of their releases, tags, and the commits of a branch,
+    // and users have a feed of their activity. Projects can be in nested groups, so the path of
+    // the project is everything before the /-/ that starts its pages. Besides gitlab.com,
+    // self-hosted instances are recognised by their site name.
+    fn gitlab(&self) -> FeedResult {
+        let is_gitlab = self.base_url.host_str() == Some("gitlab.com")
+            || self
+                .doc
+                .select_first("meta[property='og:site_name'][content='GitLab']")
+                .is_ok();
+        if !is_gitlab {
+            return Ok(Vec::new());
+        }
+
+        let segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let (project, pages) = match segments.iter().position(|segment| *segment == "-") {
+            Some(dash) => (&segments[..dash], &segments[dash + 1..]),
+            None => (&segments[..], &segments[segments.len()..]),
+        };
+        let paths = match project {
+            [] => return Ok(Vec::new()),
+            [first, ..] if GITLAB_PATHS.contains(first) => return Ok(Vec::new()),
+            [user] => vec![format!("/{}.atom", user)],
+            _ => {
+                let project = project.join("/");
+                let project = project.trim_end_matches(".git");
+                // The branch being viewed, otherwise the default branch
+                let branch = match pages {
+                    ["tree", branch, ..] | ["commits", branch, ..] => {
+                        branch.trim_end_matches(".atom")
+                    }
+                    _ => "HEAD",
+                };
+                vec![
+                    format!("/{}/-/releases.atom", project),
+                    format!("/{}/-/tags?format=atom", project),
+                    format!("/{}/-/commits/{}.atom", project, branch),
+                ]
+            }
+        };
+
+        paths
+            .iter()
+            .map(|path| {
+                Ok(Feed::candidate(
+                    self.base_url.join(path).map_err(FeedFinderError::Url)?,
+                    FeedType::Atom,
+                    Confidence::High,
+                    FeedSource::GitLab,
+                ))
+            })
+            .collect()
+    }
+
+    // Feeds from third party generators for pages on platforms that don't have feeds. The
+    // generator fetches the page so the feed is only as specific as the page, which is
+    // trimmed to the item it's about, E.g. the package rather than one of its versions.
+    fn third_party(&self) -> FeedResult {
+        let host = match self.base_url.host_str() {
+            Some(host) => host,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut feeds = Vec::new();
+        for (platform, prefix, generator) in THIRD_PARTY_FEEDS.iter() {
+            let rest = match self.base_url.path().strip_prefix(prefix) {
+                Some(rest) if host == *platform => rest,
+                _ => continue,
+            };
+            let mut segments = rest.split('/').filter(|segment| !segment.is_empty());
+            let item = match (segments.next(), segments.next()) {
+                (Some(scope), Some(name)) if scope.starts_with('@') => {
+                    format!("{}/{}", scope, name)
+                }
+                (Some(name), _) if !name.starts_with('@') => name.to_owned(),
+                _ => continue,
+            };
+            let url = format!("{}{}{}{}", generator, host, prefix, item);
+            feeds.push(Feed::candidate(
+                Url::parse(&url).map_err(FeedFinderError::Url)?,
+                FeedType::Rss,
+                Confidence::Medium,
+                FeedSource::ThirdParty,
+            ));
+        }
+
+        Ok(feeds)
+    }
+
+    // Gitea, and its fork Forgejo, have Atom feeds of the activity of repositories and users,
+    // and of the commits to a branch. Instances are recognised by known hosts, the default
+    // keywords meta tag, "go,git,self-hosted,gitea", or the "Powered by Gitea" link in the
+    // footer. Pages that merely mention Gitea, like a blog post about it, don't match these.
+    fn gitea(&self) -> FeedResult {
+        let is_gitea = self
+            .base_url
+            .host_str()
+            .map(|host| GITEA_HOSTS.contains(&host))
+            .unwrap_or(false)
+            || self
+                .doc
+                .select_first(GITEA_FINGERPRINTS)
+                .map(|_| true)
+                .unwrap_or(false);
+        if !is_gitea {
+            return Ok(Vec::new());
+        }
+
+        let segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let paths = match segments.as_slice() {
+            [] => return Ok(Vec::new()),
+            [first, ..] if GITEA_PATHS.contains(first) => return Ok(Vec::new()),
+            [user] => vec![format!("/{}.atom", user)],
+            [owner, repo, rest @ ..] => {
+                let repo = repo.trim_end_matches(".git");
+                let mut paths = vec![format!("/{}/{}.atom", owner, repo)];
+                // Viewing the files or commits of a branch
+                if let ["src", "branch", branch, ..] | ["commits", "branch", branch, ..] = rest {
+                    paths.push(format!(
+                        "/{}/{}/commits/branch/{}.atom",
+                        owner, repo, branch
+                    ));
+                }
+                paths
+            }
+        };
+
+        paths
+            .iter()
+            .map(|path| {
+                Ok(Feed::candidate(
+                    self.base_url.join(path).map_err(FeedFinderError::Url)?,
+                    FeedType::Atom,
+                    Confidence::High,
+                    FeedSource::Gitea,
+                ))
+            })
+            .collect()
+    }
+
+    // The Atom Publishing Protocol advertises a service document that lists the collections
+    // (Atom feeds) of the site. It's not a feed itself so the caller needs to fetch it to find
+    // the actual feeds, hence the low confidence.
+    fn atom_service_document(&self) -> FeedResult {
+        let mut feeds = vec![];
+        for link in self
+            .doc
+            .select("link[rel]")
+            .map_err(|_| FeedFinderError::Select)?
+        {
+            let attrs = link.attributes.borrow();
+            let is_service = rel_tokens(&attrs).iter().any(|rel| rel == "service")
+                && attrs
+                    .get("type")
+                    .map(|type_| media_type(type_) == "application/atomsvc+xml")
+                    .unwrap_or(false);
+            if let (true, Some(href)) = (is_service, attrs.get("href")) {
+                feeds.push(Feed {
+                    title: attrs.get("title").map(|title| title.to_owned()),
+                    ..Feed::candidate(
+                        self.join(href)?,
+                        FeedType::Atom,
+                        Confidence::Low,
+                        FeedSource::ServiceDocument,
+                    )
+                })
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Really Simple Discovery (RSD) documents list the publishing APIs of a site, which may
+    // include AtomPub and so lead to its feeds. Like the service document another fetch is
+    // needed to find them, and it comes after guessing as software that links to one, like
+    // WordPress, is better served by the guess.
+    fn rsd_hint(&self) -> FeedResult {
+        let mut feeds = vec![];
+        for link in self
+            .doc
+            .select("link[rel]")
+            .map_err(|_| FeedFinderError::Select)?
+        {
+            let attrs = link.attributes.borrow();
+            let is_edit_uri = attrs
+                .get("rel")
+                .map(|rel| rel.eq_ignore_ascii_case("edituri"))
+                .unwrap_or(false);
+            if let (true, Some(href)) = (is_edit_uri, attrs.get("href")) {
+                feeds.push(Feed {
+                    title: attrs.get("title").map(|title| title.to_owned()),
+                    ..Feed::candidate(
+                        self.join(href)?,
+                        FeedType::Atom,
+                        Confidence::Low,
+                        FeedSource::Rsd,
+                    )
+                })
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Some sites link to a discovery document under /.well-known/ that lists their feeds, or
+    // to host-meta which can link to them. The caller has to fetch and parse it.
+    fn well_known_links(&self) -> FeedResult {
+        let mut feeds = vec![];
+        for link in self
+            .doc
+            .select("link[href], a[href]")
+            .map_err(|_| FeedFinderError::Select)?
+        {
+            let attrs = link.attributes.borrow();
+            let url = match attrs.get("href") {
+                Some(href) if href.contains("/.well-known/") => self.join(href)?,
+                _ => continue,
+            };
+            if !WELL_KNOWN_FEEDS.contains(&url.path())
+                || feeds.iter().any(|feed: &Feed| feed.url == url)
+            {
+                continue;
+            }
+
+            feeds.push(Feed {
+                title: attrs.get("title").map(|title| title.to_owned()),
+                ..Feed::candidate(url, FeedType::Link, Confidence::Low, FeedSource::WellKnown)
+            })
+        }
+
+        Ok(feeds)
+    }
+
+    // Sitemaps aren't feeds but the pages they list may link to one, so they are the last
+    // resort when nothing else is found
+    fn link_sitemap(&self) -> FeedResult {
+        let mut feeds = vec![];
+        for link in self
+            .doc
+            .select("link[rel='sitemap']")
+            .map_err(|_| FeedFinderError::Select)?
+        {
+            let attrs = link.attributes.borrow();
+            if let Some(href) = attrs.get("href") {
+                feeds.push(Feed {
+                    title: attrs.get("title").map(|title| title.to_owned()),
+                    ..Feed::candidate(
+                        self.join(href)?,
+                        FeedType::Sitemap,
+                        Confidence::VeryLow,
+                        FeedSource::Sitemap,
+                    )
+                })
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Finds the id of the channel a YouTube video page belongs to
+    fn youtube_channel_id(&self) -> Option<String> {
+        if let Ok(meta) = self.doc.select_first("meta[itemprop='channelId']") {
+            if let Some(id) = meta.attributes.borrow().get("content") {
+                return Some(id.to_owned());
+            }
+        }
+
+        let link = self.doc.select_first("link[itemprop='url']").ok()?;
+        let attrs = link.attributes.borrow();
+        let url = Url::parse(attrs.get("href")?).ok()?;
+        match nth_path_segment(&url, 0) {
+            Some("channel") => nth_path_segment(&url, 1).map(|id| id.to_owned()),
+            _ => None,
+        }
+    }
+
+    // Searches the body for links to things that might be feeds
+    // Links are ordered by how likely they are to be a feed, so that callers trying each in
+    // turn find one sooner. A feed file extension is the strongest indication, followed by
+    // link text that names a feed format. Otherwise links stay in document order.
+    fn body_links(&self) -> FeedResult {
+        let mut feeds = vec![];
+
+        for a in self
+            .elements(&self.doc)
+            .filter(|element| &*element.name.local == "a")
+        {
+            let attrs = a.attributes.borrow();
+            if let Some(href) = attrs.get("href") {
+                if MIGHT_BE_FEED.iter().any(|hint| href.contains(hint))
+                    || has_feed_icon(a.as_node())
+                {
+                    let url = self.join(href)?;
+                    // Links back to the page itself (E.g. href="#feedback") aren't feeds
+                    if self.is_base_url(&url) {
+                        debug!(%url, "skipping link to the page itself");
+                        continue;
+                    }
+                    let path = url.path().to_lowercase();
+                    let extension = FEED_EXTENSIONS.iter().any(|ext| path.ends_with(ext));
+                    let text = a.text_contents().trim().to_lowercase();
+                    let text = FEED_LINK_TEXT.contains(&text.as_str());
+                    let widget = self.config.subscribe_widgets && in_subscribe_widget(a.as_node());
+                    let confidence = if widget {
+                        Confidence::High
+                    } else {
+                        Confidence::Medium
+                    };
+                    feeds.push((
+                        (widget, extension, text),
+                        Feed::candidate(url, FeedType::Link, confidence, FeedSource::BodyLink),
+                    ))
+                }
+            }
+        }
+
+        feeds.sort_by_key(|(rank, _)| Reverse(*rank));
+        Ok(feeds.into_iter().map(|(_, feed)| feed).collect())
+    }
+
+    // Some site generators leave hints about the feed in comments, E.g. <!-- feed: /rss.xml -->
+    // so look for URLs in comments that might be feeds
+    fn comment_links(&self) -> FeedResult {
+        let mut feeds: Vec<Feed> = Vec::new();
+        'scan: for node in self.doc.descendants() {
+            let comment = match node.as_comment() {
+                Some(comment) => comment.borrow(),
+                None => continue,
+            };
+            for word in comment.split_whitespace() {
+                let href = word.trim_end_matches(&[',', '.', ';', ')'][..]);
+                if !(href.starts_with('/')
+                    || href.starts_with("http://")
+                    || href.starts_with("https://"))
+                    || !MIGHT_BE_FEED.iter().any(|hint| href.contains(hint))
+                {
+                    continue;
+                }
+                let url = match self.join(href) {
+                    Ok(url) => url,
+                    Err(_) => continue,
+                };
+                if !feeds.iter().any(|feed| feed.url == url) {
+                    feeds.push(Feed::candidate(
+                        url,
+                        FeedType::Guess,
+                        Confidence::VeryLow,
+                        FeedSource::HtmlComment,
+                    ));
+                    if self.enough(feeds.len()) {
+                        break 'scan;
+                    }
+                }
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Next.js embeds the initial props of the page as JSON in <script id="__NEXT_DATA__">,
+    // which sometimes includes the feed, E.g. {"props":{"pageProps":{"rssUrl":"/rss.xml"}}}
+    fn next_data(&self) -> FeedResult {
+        let script = match self.doc.select_first("script#__NEXT_DATA__") {
+            Ok(script) => script,
+            Err(()) => return Ok(Vec::new()),
+        };
+        let data: serde_json::Value = match serde_json::from_str(&script.text_contents()) {
+            Ok(data) => data,
+            Err(_) => {
+                debug!("skipping invalid __NEXT_DATA__");
+                return Ok(Vec::new());
+            }
+        };
+
+        let mut values = vec![&data];
+        let mut feeds: Vec<Feed> = Vec::new();
+        while let Some(value) = values.pop() {
+            let href = match value {
+                serde_json::Value::String(href) => href,
+                serde_json::Value::Array(items) => {
+                    values.extend(items.iter().rev());
+                    continue;
+                }
+                serde_json::Value::Object(fields) => {
+                    values.extend(fields.values().rev());
+                    continue;
+                }
+                _ => continue,
+            };
+            // The paths of the page's own scripts and data are under /_next/
+            if !(href.starts_with('/')
+                || href.starts_with("http://")
+                || href.starts_with("https://"))
+                || href.contains("/_next/")
+                || !MIGHT_BE_FEED.iter().any(|hint| href.contains(hint))
+            {
+                continue;
+            }
+            let url = match self.join(href) {
+                Ok(url) => url,
+                Err(_) => continue,
+            };
+            if !feeds.iter().any(|feed| feed.url == url) {
+                feeds.push(Feed::candidate(
+                    url,
+                    FeedType::Guess,
+                    Confidence::Low,
+                    FeedSource::NextData,
+                ));
+                if self.enough(feeds.len()) {
+                    break;
+                }
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Blogs describe themselves with Schema.org metadata in JSON-LD, which can reference the
+    // feed, E.g. {"@type":"Blog","hasPart":{"@type":"DataFeed","url":"/feed.xml"}}. The
+    // properties also hold other URLs, like the blog's home page, so only ones that look like
+    // feeds are used.
+    fn schema_org(&self) -> FeedResult {
+        let mut feeds: Vec<Feed> = Vec::new();
+        'scan: for script in self
+            .doc
+            .select("script[type='application/ld+json']")
+            .map_err(|_| FeedFinderError::Select)?
+        {
+            let data: serde_json::Value = match serde_json::from_str(&script.text_contents()) {
+                Ok(data) => data,
+                Err(_) => {
+                    debug!("skipping invalid JSON-LD");
+                    continue;
+                }
+            };
+
+            let mut values = vec![&data];
+            let mut hrefs = Vec::new();
+            while let Some(value) = values.pop() {
+                let fields = match value {
+                    serde_json::Value::Array(items) => {
+                        values.extend(items.iter().rev());
+                        continue;
+                    }
+                    serde_json::Value::Object(fields) => fields,
+                    _ => continue,
+                };
+                if let Some(graph) = fields.get("@graph") {
+                    values.push(graph);
+                }
+                let is_blog = match fields.get("@type") {
+                    Some(serde_json::Value::String(type_)) => {
+                        matches!(type_.as_str(), "Blog" | "BlogPosting")
+                    }
+                    Some(serde_json::Value::Array(types)) => types
+                        .iter()
+                        .any(|type_| matches!(type_.as_str(), Some("Blog") | Some("BlogPosting"))),
+                    _ => false,
+                };
+                if is_blog {
+                    for key in &["hasPart", "potentialAction", "url"] {
+                        if let Some(value) = fields.get(*key) {
+                            schema_org_urls(value, &mut hrefs);
+                        }
+                    }
+                }
+            }
+
+            for href in hrefs {
+                if !MIGHT_BE_FEED.iter().any(|hint| href.contains(hint)) {
+                    continue;
+                }
+                let url = match self.join(href) {
+                    Ok(url) => url,
+                    Err(_) => continue,
+                };
+                if !feeds.iter().any(|feed| feed.url == url) {
+                    feeds.push(Feed::candidate(
+                        url,
+                        FeedType::Guess,
+                        Confidence::Low,
+                        FeedSource::SchemaOrg,
+                    ));
+                    if self.enough(feeds.len()) {
+                        break 'scan;
+                    }
+                }
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Some legacy PHP blog software generated feeds from a form, E.g.
+    // <form action="/generate-rss"><input type="hidden" name="cat" value="3"></form>. Submitting
+    // it with GET includes the hidden inputs in the query, so they're added to the URL too.
+    fn body_form_links(&self) -> FeedResult {
+        let mut feeds: Vec<Feed> = Vec::new();
+        for form in self
+            .doc
+            .select("form[action]")
+            .map_err(|_| FeedFinderError::Select)?
+        {
+            let attrs = form.attributes.borrow();
+            let action = attrs.get("action").unwrap_or_default();
+            let is_get = attrs
+                .get("method")
+                .map(|method| method.eq_ignore_ascii_case("get"))
+                .unwrap_or(true);
+            if !is_get
+                || !MIGHT_BE_FEED
+                    .iter()
+                    .any(|hint| action.to_lowercase().contains(hint))
+            {
+                continue;
+            }
+
+            let mut url = self.join(action)?;
+            let inputs = form
+                .as_node()
+                .select("input[type='hidden'][name]")
+                .map_err(|_| FeedFinderError::Select)?;
+            for input in inputs {
+                let input = input.attributes.borrow();
+                if let Some(name) = input.get("name") {
+                    url.query_pairs_mut()
+                        .append_pair(name, input.get("value").unwrap_or_default());
+                }
+            }
+            if !feeds.iter().any(|feed| feed.url == url) {
+                feeds.push(Feed {
+                    title: attrs.get("title").map(|title| title.to_owned()),
+                    ..Feed::candidate(url, FeedType::Link, Confidence::Low, FeedSource::Form)
+                });
+                if self.enough(feeds.len()) {
+                    break;
+                }
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Whether a source has found as many candidates as the caller wants, so it can stop early
+    fn enough(&self, found: usize) -> bool {
+        self.config
+            .max_results
+            .map(|max| found >= max)
+            .unwrap_or(false)
+    }
+
+    // The elements of the node in document order, stopping at the scan limit if there is one
+    fn elements(
+        &self,
+        node: &kuchiki::NodeRef,
+    ) -> impl Iterator<Item = kuchiki::NodeDataRef<kuchiki::ElementData>> {
+        node.descendants()
+            .elements()
+            .take(self.config.scan_limit.unwrap_or(usize::MAX))
+    }
+
+    // The elements of the document followed by those in the content of any <template>
+    // elements, which the parser keeps in separate fragments that aren't reachable from the
+    // document. The scan limit applies to all of them together.
+    fn all_elements(&self) -> impl Iterator<Item = kuchiki::NodeDataRef<kuchiki::ElementData>> {
+        let mut fragments = VecDeque::new();
+        fragments.push_back(self.doc.clone());
+        let mut elements: Option<kuchiki::iter::Elements<kuchiki::iter::Descendants>> = None;
+        iter::from_fn(move || loop {
+            if let Some(element) = elements.as_mut().and_then(Iterator::next) {
+                if let Some(contents) = &element.template_contents {
+                    fragments.push_back(contents.clone());
+                }
+                return Some(element);
+            }
+            elements = Some(fragments.pop_front()?.descendants().elements());
+        })
+        .take(self.config.scan_limit.unwrap_or(usize::MAX))
+    }
+
+    // Resolves a link relative to the base URL. Links are sometimes written without a scheme,
+    // E.g. href="www.example.com/feed.rss", which would otherwise be treated as a relative
+    // path so these get the scheme of the base URL instead.
+    fn join(&self, href: &str) -> Result<Url, FeedFinderError> {
+        let host = href.split(&['/', '?', '#'][..]).next();
+        let looks_like_host = match host {
+            Some(host) if !host.contains(':') => {
+                host.starts_with("www.") || Some(host) == self.base_url.host_str()
+            }
+            _ => false,
+        };
+
+        if looks_like_host {
+            Url::parse(&format!("{}://{}", self.base_url.scheme(), href))
+        } else {
+            self.base_url.join(href)
+        }
+        .map_err(FeedFinderError::Url)
+    }
+
+    // Checks if the URL refers to the page itself, ignoring any fragment
+    fn is_base_url(&self, url: &Url) -> bool {
+        let mut url = url.clone();
+        url.set_fragment(None);
+        let mut base_url = self.base_url.clone();
+        base_url.set_fragment(None);
+        url == base_url
+    }
+
+    // Well this sure isn't pretty. TODO: Clean up
+    fn guess_segments(&self, feed_files: &[&str]) -> FeedResult {
+        let mut feeds = Vec::new();
+
+        if let Some(segments) = self.base_url.path_segments() {
+            let mut remaining_segments = segments.collect::<Vec<_>>();
+            let mut segments = vec!["", ""];
+
+            loop {
+                let index = segments.len() - 1;
+                for feed_file in feed_files {
+                    segments[index] = feed_file;
+                    let url = self
+                        .base_url
+                        .join(&segments.join("/"))
+                        .map_err(FeedFinderError::Url)?;
+                    feeds.push(Feed::candidate(
+                        url,
+                        FeedType::Guess,
+                        Confidence::Low,
+                        FeedSource::Guess,
+                    ));
+                }
+
+                if remaining_segments.is_empty() {
+                    break;
+                }
+
+                let segment = remaining_segments.remove(0);
+                if segment.is_empty() {
+                    // Skip empty strings, which should only occur as the last element
+                    break;
+                }
+
+                segments.insert(index, segment);
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Resolves a path against the root of the site, which is / unless configured otherwise
+    fn join_root(&self, path: &str) -> Result<Url, FeedFinderError> {
+        let root = match &self.config.site_root {
+            Some(root) => format!("{}/", root.trim_end_matches('/')),
+            None => String::from("/"),
+        };
+        self.base_url
+            .join(&root)
+            .and_then(|root| root.join(path.trim_start_matches('/')))
+            .map_err(FeedFinderError::Url)
+    }
+
+    // Guesses at each of the paths, which are resolved against the root of the site
+    fn guess_paths<S: AsRef<str>>(&self, paths: &[S]) -> FeedResult {
+        paths
+            .iter()
+            .map(|path| {
+                Ok(Feed::candidate(
+                    self.join_root(path.as_ref())?,
+                    FeedType::Guess,
+                    Confidence::Low,
+                    FeedSource::Guess,
+                ))
+            })
+            .collect()
+    }
+
+    // Pelican publishes a feed of all posts as well as one for each category
+    fn guess_pelican(&self) -> FeedResult {
+        let mut paths = Vec::new();
+        if let (Some("category"), Some(name)) = (
+            nth_path_segment(self.base_url, 0),
+            nth_path_segment(self.base_url, 1),
+        ) {
+            let name = name.trim_end_matches(".html");
+            if !name.is_empty() {
+                paths.push(format!("/feeds/{}.atom.xml", name));
+            }
+        }
+        paths.push(String::from("/feeds/all.atom.xml"));
+
+        self.guess_paths(&paths)
+    }
+
+    // Category, tag, author, and date archives have a feed of their posts at feed/ under the
+    // archive, which comes before the feed of the whole site. A post's permalink can also start
+    // with a date, so a date archive is only a path of numbers.
+    fn guess_wordpress(&self) -> FeedResult {
+        let mut segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        // Later pages of an archive, like /category/news/page/2/
+        if let [.., "page", number] = segments.as_slice() {
+            if number.bytes().all(|byte| byte.is_ascii_digit()) {
+                segments.truncate(segments.len() - 2);
+            }
+        }
+
+        let is_number = |segment: &&str| segment.bytes().all(|byte| byte.is_ascii_digit());
+        let archive_end = match segments
+            .iter()
+            .position(|segment| matches!(*segment, "category" | "tag" | "author"))
+        {
+            // Categories can be nested, tags and authors can't
+            Some(kind) if segments[kind] == "category" && kind + 1 < segments.len() => {
+                Some(segments.len())
+            }
+            Some(kind) if kind + 2 == segments.len() => Some(segments.len()),
+            Some(_) => None,
+            None => segments
+                .iter()
+                .position(|segment| segment.len() == 4 && is_number(segment))
+                .filter(|&year| {
+                    segments.len() - year <= 3 && segments[year..].iter().all(is_number)
+                })
+                .map(|_| segments.len()),
+        };
+
+        let mut feeds = Vec::new();
+        if let Some(end) = archive_end {
+            feeds.push(Feed::candidate(
+                self.join(&format!("/{}/feed/", segments[..end].join("/")))?,
+                FeedType::Guess,
+                Confidence::Low,
+                FeedSource::Guess,
+            ));
+        }
+        feeds.extend(self.guess_paths(&[self.check_cms_versions("wordpress").unwrap_or("/feed")])?);
+        if let Some(api) = self.wordpress_rest_api()? {
+            feeds.extend(self.guess_paths(&["/feed/atom"])?);
+            feeds.push(Feed::candidate(
+                api,
+                FeedType::Json,
+                Confidence::Low,
+                FeedSource::Guess,
+            ));
+        }
+
+        Ok(feeds)
+    }
+
+    // WordPress 4.4 and later link to the root of the REST API, which lists posts as JSON at
+    // wp/v2/posts. Without pretty permalinks the route is given by the rest_route parameter.
+    fn wordpress_rest_api(&self) -> Result<Option<Url>, FeedFinderError> {
+        let link = match self
+            .doc
+            .select_first("link[rel='https://api.w.org/'][href]")
+        {
+            Ok(link) => link,
+            Err(()) => return Ok(None),
+        };
+        let root = self.join(link.attributes.borrow().get("href").unwrap_or_default())?;
+
+        let mut api = if root.query_pairs().any(|(name, _)| name == "rest_route") {
+            let mut api = root.clone();
+            api.query_pairs_mut()
+                .clear()
+                .extend_pairs(root.query_pairs().filter(|(name, _)| name != "rest_route"))
+                .append_pair("rest_route", "/wp/v2/posts");
+            api
+        } else if root.path().ends_with('/') {
+            root.join("wp/v2/posts").map_err(FeedFinderError::Url)?
+        } else {
+            root.join(&format!("{}/wp/v2/posts", root.path()))
+                .map_err(FeedFinderError::Url)?
+        };
+        api.query_pairs_mut().append_pair("_embed", "");
+
+        Ok(Some(api))
+    }
+
+    // Each Shopify blog has a feed at /blogs/<handle>.atom. Its articles are under the blog's
+    // path, and stores with more than one language prefix it with the locale, E.g.
+    // /fr/blogs/news/article. Stores get a blog called news by default.
+    fn guess_shopify(&self) -> FeedResult {
+        let segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let blog = segments
+            .iter()
+            .position(|segment| *segment == "blogs")
+            .filter(|&blogs| blogs + 1 < segments.len());
+        let path = match blog {
+            Some(blogs) => format!("/{}.atom", segments[..blogs + 2].join("/")),
+            None => String::from("/blogs/news.atom"),
+        };
+
+        self.guess_paths(&[path])
+    }
+
+    // Weebly blogs are a page of the site, most often /blog, with posts under it and a feed at
+    // feed under it. Older sites used /1. Other pages of the site end in .html, so a first
+    // segment without an extension is taken to be a blog page.
+    fn guess_weebly(&self) -> FeedResult {
+        let mut paths = Vec::new();
+        if let Some(page) = nth_path_segment(self.base_url, 0) {
+            if !page.is_empty() && !page.contains('.') {
+                paths.push(format!("/{}/feed", page));
+            }
+        }
+        for path in &["/blog/feed", "/1/feed"] {
+            if !paths.iter().any(|guessed| guessed == path) {
+                paths.push(String::from(*path));
+            }
+        }
+
+        self.guess_paths(&paths)
+    }
+
+    // Each LiveJournal journal and community has RSS and Atom feeds under data/ at its root,
+    // which is its own subdomain, or /users/<name>/ or /community/<name>/ on www. Other pages
+    // on www aggregate many journals so have no feed.
+    fn guess_livejournal(&self) -> FeedResult {
+        let root = match self.base_url.host_str() {
+            Some("www.livejournal.com") => match (
+                nth_path_segment(self.base_url, 0),
+                nth_path_segment(self.base_url, 1),
+            ) {
+                (Some(kind), Some(name))
+                    if (kind == "users" || kind == "community") && !name.is_empty() =>
+                {
+                    format!("/{}/{}", kind, name)
+                }
+                _ => return Ok(Vec::new()),
+            },
+            _ => String::new(),
+        };
+
+        self.guess_paths(&[format!("{}/data/rss", root), format!("{}/data/atom", root)])
+    }
+
+    // Dreamwidth is a fork of LiveJournal with the same feeds under data/ at the root of each
+    // journal's subdomain, which can also be filtered to a tag. The front page and other pages
+    // on www aren't journals.
+    fn guess_dreamwidth(&self) -> FeedResult {
+        if self.base_url.host_str() == Some("www.dreamwidth.org") {
+            return Ok(Vec::new());
+        }
+
+        let mut paths = Vec::new();
+        if let (Some("tag"), Some(tag)) = (
+            nth_path_segment(self.base_url, 0),
+            nth_path_segment(self.base_url, 1),
+        ) {
+            if !tag.is_empty() {
+                paths.push(format!("/data/rss?tag={}", tag));
+            }
+        }
+        paths.push(String::from("/data/rss"));
+        paths.push(String::from("/data/atom"));
+
+        self.guess_paths(&paths)
+    }
+
+    // Typepad blogs are usually under a path of the account's domain, E.g.
+    // example.typepad.com/my_blog/, with the feeds at the root of the blog. Entries are under
+    // dated paths like /2024/05/entry.html, which are at the root on custom domains.
+    fn guess_typepad(&self) -> FeedResult {
+        let root = match nth_path_segment(self.base_url, 0) {
+            Some(blog)
+                if !blog.is_empty()
+                    && !blog.contains('.')
+                    && !blog.bytes().all(|byte| byte.is_ascii_digit()) =>
+            {
+                format!("/{}", blog)
+            }
+            _ => String::new(),
+        };
+
+        self.guess_paths(&[format!("{}/atom.xml", root), format!("{}/rss.xml", root)])
+    }
+
+    // Grav's feed plugin serves a feed of a collection with the feed's extension on the path of
+    // the collection. The page is either the collection itself, like /blog, or one of its
+    // items, like /blog/my-post.
+    fn guess_grav(&self) -> FeedResult {
+        let segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let collection = match segments.len() {
+            0 => None,
+            1 => Some(segments.join("/")),
+            len => Some(segments[..len - 1].join("/")),
+        };
+
+        let mut paths = Vec::new();
+        if let Some(collection) = collection {
+            paths.push(format!("/{}.rss", collection));
+            paths.push(format!("/{}.atom", collection));
+        }
+        paths.push(String::from("/feed.rss"));
+
+        self.guess_paths(&paths)
+    }
+
+    // Each Buttondown newsletter has a feed of its archive under its path
+    fn guess_buttondown(&self) -> FeedResult {
+        match nth_path_segment(self.base_url, 0) {
+            Some(newsletter) if !newsletter.is_empty() => {
+                self.guess_paths(&[format!("/{}/rss", newsletter)])
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    // Medium has a feed for each user, publication, and tag at /feed/<name> on medium.com, and
+    // one at /feed on subdomains and custom domains. Member-only stories are often reached via
+    // a sign in redirect, E.g. /m/global-identity-provider/redirect?redirectUrl=..., so its
+    // target is used instead. Feeds are derived from the path alone, leaving behind tracking
+    // parameters like ?source=.
+    fn guess_medium(&self) -> FeedResult {
+        let target;
+        let page = if nth_path_segment(self.base_url, 0) == Some("m") {
+            target = match self
+                .base_url
+                .query_pairs()
+                .find(|(key, _)| key == "redirectUrl")
+                .and_then(|(_, url)| Url::parse(&url).ok())
+            {
+                Some(url) => url,
+                None => return Ok(Vec::new()),
+            };
+            &target
+        } else {
+            self.base_url
+        };
+
+        let path = match page.host_str() {
+            Some("medium.com") | Some("www.medium.com") => match (
+                nth_path_segment(page, 0),
+                nth_path_segment(page, 1).filter(|name| !name.is_empty()),
+            ) {
+                (Some("tag"), Some(tag)) => format!("/feed/tag/{}", tag),
+                (Some(name), _) if !MEDIUM_PATHS.contains(&name) => format!("/feed/{}", name),
+                _ => return Ok(Vec::new()),
+            },
+            _ => String::from("/feed"),
+        };
+        Ok(vec![Feed::candidate(
+            page.join(&path).map_err(FeedFinderError::Url)?,
+            FeedType::Guess,
+            Confidence::Low,
+            FeedSource::Guess,
+        )])
+    }
+
+    // The archive of a Mailchimp list is at /home/?u=<account>&id=<list>, with its feed at
+    // /feed with the same parameters
+    fn guess_mailchimp(&self) -> FeedResult {
+        let param = |name| {
+            self.base_url
+                .query_pairs()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value.into_owned())
+        };
+        match (nth_path_segment(self.base_url, 0), param("u"), param("id")) {
+            (Some("home"), Some(account), Some(list)) => {
+                let mut url = self.join_root("/feed")?;
+                url.query_pairs_mut()
+                    .append_pair("u", &account)
+                    .append_pair("id", &list);
+                Ok(vec![Feed::candidate(
+                    url,
+                    FeedType::Guess,
+                    Confidence::Low,
+                    FeedSource::Guess,
+                )])
+            }
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    // Lemmy has feeds for each community and user, and for the local and all timelines
+    fn guess_lemmy(&self) -> FeedResult {
+        match (
+            nth_path_segment(self.base_url, 0),
+            nth_path_segment(self.base_url, 1).filter(|name| !name.is_empty()),
+        ) {
+            (Some("c"), Some(community)) => {
+                self.guess_paths(&[format!("/feeds/c/{}.xml", community)])
+            }
+            (Some("u"), Some(user)) => self.guess_paths(&[format!("/feeds/u/{}.xml", user)]),
+            _ => self.guess_paths(&["/feeds/local.xml", "/feeds/all.xml"]),
+        }
+    }
+
+    // Forem has feeds for each user and organisation, including on their articles, and for
+    // each tag
+    fn guess_forem(&self) -> FeedResult {
+        match (
+            nth_path_segment(self.base_url, 0),
+            nth_path_segment(self.base_url, 1).filter(|name| !name.is_empty()),
+        ) {
+            (Some("t"), Some(tag)) => self.guess_paths(&[format!("/feed/tag/{}", tag)]),
+            (Some(name), _) if !FOREM_PATHS.contains(&name) && name != "t" => {
+                self.guess_paths(&[format!("/feed/{}", name)])
+            }
+            _ => self.guess_paths(&["/feed"]),
+        }
+    }
+
+    // Nitter has a feed for each profile, each of its tabs, lists, and searches, at rss under
+    // the path of the page
+    fn guess_nitter(&self) -> FeedResult {
+        let segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| {
+                segments
+                    .filter(|segment| !segment.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let query = self
+            .base_url
+            .query()
+            .map(|query| format!("?{}", query))
+            .unwrap_or_default();
+        let path = match segments.as_slice() {
+            ["search"] => format!("/search/rss{}", query),
+            ["i", "lists", id, ..] => format!("/i/lists/{}/rss", id),
+            ["i", ..] | ["settings", ..] | ["about", ..] | [] => return Ok(Vec::new()),
+            [user, tab, ..] if matches!(*tab, "with_replies" | "media" | "search") => {
+                format!("/{}/{}/rss{}", user, tab, query)
+            }
+            [user, ..] => format!("/{}/rss", user),
+        };
+
+        self.guess_paths(&[path])
+    }
+
+    // Tag pages have a feed of the posts with that tag as well as the one for the whole blog
+    fn guess_tumblr(&self) -> FeedResult {
+        let mut paths = Vec::new();
+        if let (Some("tagged"), Some(tag)) = (
+            nth_path_segment(self.base_url, 0),
+            nth_path_segment(self.base_url, 1),
+        ) {
+            if !tag.is_empty() {
+                paths.push(format!("/tagged/{}/rss", tag));
+            }
+        }
+        paths.push(String::from("/rss"));
+
+        self.guess_paths(&paths)
+    }
+
+    // Hugo's feed file name is configurable, so after its default try some other common names.
+    // Sections and taxonomy terms, like /tags/rust/, have their own feed, so each level of the
+    // path is tried.
+    fn guess_hugo(&self) -> FeedResult {
+        if let Some(path) = self.check_cms_versions("hugo") {
+            return self.guess_segments(&[path.trim_start_matches('/')]);
+        }
+
+        let mut feeds = self.guess_segments(&["index.xml", "rss.xml", "feed.xml"])?;
+        for feed in feeds.iter_mut() {
+            if !feed.url.path().ends_with("/index.xml") {
+                feed.confidence = Confidence::VeryLow;
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Gatsby sites are single page apps so the base URL is often a client side route that has
+    // nothing to do with where the feed is, so only guess at the root.
+    fn guess_gatsby(&self) -> FeedResult {
+        let mut feeds = self.guess_paths(&["/rss.xml"])?;
+        for mut feed in self.guess_paths(&["/feed.xml"])? {
+            feed.confidence = Confidence::VeryLow;
+            feeds.push(feed);
+        }
+
+        Ok(feeds)
+    }
+
+    // Multilingual Nikola sites put the feeds for each language under a language code path,
+    // which are more specific than the default language feeds at the root.
+    fn guess_nikola(&self) -> FeedResult {
+        let feed_files = ["rss.xml", "index.atom"];
+        let mut feeds = match nth_path_segment(self.base_url, 0) {
+            Some(lang) if lang.len() == 2 && lang.chars().all(|c| c.is_ascii_lowercase()) => {
+                let paths = feed_files
+                    .iter()
+                    .map(|feed_file| format!("/{}/{}", lang, feed_file))
+                    .collect::<Vec<_>>();
+                self.guess_paths(&paths)?
+            }
+            _ => Vec::new(),
+        };
+        for feed in self.guess_segments(&feed_files)? {
+            if !feeds.iter().any(|existing| existing.url == feed.url) {
+                feeds.push(feed);
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    // Discourse has feeds for topics and categories at the path of the topic or category with
+    // .rss appended. Anything trailing the numeric id, like a post number, is dropped.
+    fn guess_discourse(&self) -> FeedResult {
+        let segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| segments.collect::<Vec<_>>())
+            .unwrap_or_default();
+        let start = match &self.config.site_root {
+            Some(root) => root.split('/').filter(|s| !s.is_empty()).count(),
+            None => 0,
+        };
+        let path = match segments.get(start..) {
+            Some([kind @ "t", rest @ ..]) | Some([kind @ "c", rest @ ..]) => rest
+                .iter()
+                .position(|segment| {
+                    !segment.is_empty() && segment.chars().all(|c| c.is_ascii_digit())
+                })
+                .map(|id| format!("/{}/{}.rss", kind, rest[..=id].join("/"))),
+            _ => None,
+        };
+
+        self.guess_paths(&[path.unwrap_or_else(|| String::from("/latest.rss"))])
+    }
+
+    // Forum software pages are PHP scripts in the board root, like viewforum.php, so the root
+    // is the directory containing the script in the path rather than assumed to be /.
+    fn script_root(&self) -> Result<Url, FeedFinderError> {
+        if self.config.site_root.is_some() {
+            return self.join_root("");
+        }
+
+        let segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| segments.collect::<Vec<_>>())
+            .unwrap_or_default();
+        let end = segments
+            .iter()
+            .position(|segment| segment.ends_with(".php"))
+            .unwrap_or_else(|| segments.len().saturating_sub(1));
+        let path = segments[..end]
+            .iter()
+            .map(|segment| format!("/{}", segment))
+            .collect::<String>();
+        self.base_url
+            .join(&format!("{}/", path))
+            .map_err(FeedFinderError::Url)
+    }
+
+    // Guesses at each of the paths, which are resolved against root
+    fn guess_relative(&self, root: &Url, paths: &[String]) -> FeedResult {
+        paths
+            .iter()
+            .map(|path| {
+                Ok(Feed::candidate(
+                    root.join(path).map_err(FeedFinderError::Url)?,
+                    FeedType::Guess,
+                    Confidence::Low,
+                    FeedSource::Guess,
+                ))
+            })
+            .collect()
+    }
+
+    // The forum id of a page viewing a forum, from the f query parameter
+    fn forum_id(&self) -> Option<String> {
+        self.base_url
+            .query_pairs()
+            .find(|(name, _)| name == "f")
+            .map(|(_, id)| id.into_owned())
+    }
+
+    // phpBB 3.1 and later serve feeds from app.php/feed while 3.0 has feed.php, so guess both,
+    // forum feed first when viewing a forum.
+    fn guess_phpbb(&self) -> FeedResult {
+        let mut paths = Vec::new();
+        if let Some(forum) = self.forum_id() {
+            paths.push(format!("app.php/feed/forum/{}", forum));
+            paths.push(format!("feed.php?f={}", forum));
+        }
+        paths.push(String::from("app.php/feed"));
+        paths.push(String::from("feed.php"));
+
+        self.guess_relative(&self.script_root()?, &paths)
+    }
+
+    // vBulletin serves all its feeds from external.php, with forumids selecting a forum
+    fn guess_vbulletin(&self) -> FeedResult {
+        let mut paths = Vec::new();
+        let script = self
+            .base_url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back());
+        if let (Some("forumdisplay.php"), Some(forum)) = (script, self.forum_id()) {
+            paths.push(format!("external.php?type=RSS2&forumids={}", forum));
+        }
+        paths.push(String::from("external.php?type=RSS2"));
+
+        self.guess_relative(&self.script_root()?, &paths)
+    }
+
+    // MediaWiki has a feed of recent changes to the whole wiki, and of the history of each
+    // page. These are served by index.php, which is in the script path alongside load.php.
+    fn guess_mediawiki(&self) -> FeedResult {
+        let load = self
+            .doc
+            .select("link[href*='load.php'], script[src*='load.php']")
+            .map_err(|_| FeedFinderError::Select)?
+            .find_map(|asset| {
+                let attrs = asset.attributes.borrow();
+                attrs
+                    .get("href")
+                    .or_else(|| attrs.get("src"))
+                    .map(String::from)
+            });
+        let index = match load {
+            Some(load) => self
+                .join(&load)?
+                .join("index.php")
+                .map_err(FeedFinderError::Url)?,
+            None if self.config.site_root.is_some() => self.join_root("index.php")?,
+            None => self
+                .base_url
+                .join("index.php")
+                .map_err(FeedFinderError::Url)?,
+        };
+
+        let mut feeds = Vec::new();
+        let title = match (
+            self.base_url
+                .query_pairs()
+                .find(|(name, _)| name == "title"),
+            nth_path_segment(self.base_url, 0),
+        ) {
+            (Some((_, title)), _) => Some(title.into_owned()),
+            (None, Some("wiki")) => self.base_url.path().get("/wiki/".len()..).map(|title| {
+                percent_encoding::percent_decode_str(title)
+                    .decode_utf8_lossy()
+                    .into_owned()
+            }),
+            _ => None,
+        };
+        if let Some(title) =
+            title.filter(|title| !title.is_empty() && !title.starts_with("Special:"))
+        {
+            let mut url = index.clone();
+            url.query_pairs_mut()
+                .append_pair("title", &title)
+                .append_pair("action", "history")
+                .append_pair("feed", "atom");
+            feeds.push(url);
+        }
+        let mut url = index;
+        url.query_pairs_mut()
+            .append_pair("title", "Special:RecentChanges")
+            .append_pair("feed", "atom");
+        feeds.push(url);
+
+        Ok(feeds
+            .into_iter()
+            .map(|url| Feed::candidate(url, FeedType::Guess, Confidence::Low, FeedSource::Guess))
+            .collect())
+    }
+
+    // DokuWiki has a feed of recent changes at feed.php in the wiki root, which is where the
+    // lib/exe scripts the page references are. Pages in a namespace get the feed for that
+    // namespace first.
+    fn guess_dokuwiki(&self) -> FeedResult {
+        let asset = self
+            .doc
+            .select("link[href*='lib/exe/'], script[src*='lib/exe/']")
+            .map_err(|_| FeedFinderError::Select)?
+            .find_map(|asset| {
+                let attrs = asset.attributes.borrow();
+                attrs
+                    .get("href")
+                    .or_else(|| attrs.get("src"))
+                    .and_then(|href| href.find("lib/exe/").map(|index| href[..index].to_owned()))
+            });
+        let root = match asset {
+            Some(root) if self.config.site_root.is_none() => self.join(&root)?,
+            _ => self.script_root()?,
+        };
+
+        let mut paths = Vec::new();
+        let namespace = self
+            .base_url
+            .query_pairs()
+            .find(|(name, _)| name == "id")
+            .and_then(|(_, id)| id.rfind(':').map(|index| id[..index].to_owned()));
+        if let Some(namespace) = namespace.filter(|namespace| !namespace.is_empty()) {
+            paths.push(format!("feed.php?ns={}", namespace));
+        }
+        paths.push(String::from("feed.php"));
+
+        self.guess_relative(&root, &paths)
+    }
+
+    // Mastodon profiles have an RSS feed at the profile URL with .rss appended. The profile is
+    // the first path segment, which also leads the URLs of the profile's tabs and posts.
+    fn guess_mastodon(&self) -> FeedResult {
+        let profile = match nth_path_segment(self.base_url, 0) {
+            Some(profile) if profile.starts_with('@') && profile.len() > 1 => profile,
+            _ => return Ok(Vec::new()),
+        };
+
+        Ok(vec![Feed::candidate(
+            self.base_url
+                .join(&format!("/{}.rss", profile))
+                .map_err(FeedFinderError::Url)?,
+            FeedType::Rss,
+            Confidence::Low,
+            FeedSource::Guess,
+        )])
+    }
+
+    // Pleroma and Akkoma users have an Atom feed under /users/<name>, which is also where
+    // their posts are. Profiles are also found at /@<name>. Other pages, like the front page
+    // of the instance, have no feed.
+    fn guess_pleroma(&self) -> FeedResult {
+        let name = match (
+            nth_path_segment(self.base_url, 0),
+            nth_path_segment(self.base_url, 1),
+        ) {
+            (Some("users"), Some(name)) if !name.is_empty() => name,
+            (Some(profile), _) if profile.starts_with('@') && profile.len() > 1 => &profile[1..],
+            _ => return Ok(Vec::new()),
+        };
+
+        Ok(vec![Feed::candidate(
+            self.base_url
+                .join(&format!("/users/{}/feed.atom", name))
+                .map_err(FeedFinderError::Url)?,
+            FeedType::Atom,
+            Confidence::Low,
+            FeedSource::Guess,
+        )])
+    }
+
+    // Pixelfed users have an Atom feed at /users/<name>.atom. Profiles are at /<name> or
+    // /users/<name> and photos at /p/<name>/<id>. Photos with only an id in the URL link to
+    // their ActivityPub object, which includes the name.
+    fn guess_pixelfed(&self) -> FeedResult {
+        let activity_pub = self
+            .doc
+            .select("link[rel='alternate'][type='application/activity+json']")
+            .map_err(|_| FeedFinderError::Select)?
+            .find_map(|link| link.attributes.borrow().get("href").map(String::from))
+            .and_then(|href| self.join(&href).ok());
+        let name = match (
+            nth_path_segment(self.base_url, 0),
+            nth_path_segment(self.base_url, 1),
+            nth_path_segment(self.base_url, 2),
+        ) {
+            (Some("users"), Some(name), _) => Some(name.to_owned()),
+            (Some("p"), Some(name), Some(_)) => Some(name.to_owned()),
+            (Some("p"), _, _) => activity_pub.as_ref().and_then(|url| {
+                match (nth_path_segment(url, 0), nth_path_segment(url, 1)) {
+                    (Some("p"), Some(name)) | (Some("users"), Some(name)) => Some(name.to_owned()),
+                    _ => None,
+                }
+            }),
+            // Other single segment paths are profiles, apart from the app's own pages
+            (Some(name), None, _) | (Some(name), Some(""), _)
+                if !PIXELFED_PATHS.contains(&name) =>
+            {
+                Some(name.to_owned())
+            }
+            _ => None,
+        };
+
+        match name.filter(|name| !name.is_empty()) {
+            Some(name) => Ok(vec![Feed::candidate(
+                self.base_url
+                    .join(&format!("/users/{}.atom", name))
+                    .map_err(FeedFinderError::Url)?,
+                FeedType::Atom,
+                Confidence::Low,
+                FeedSource::Guess,
+            )]),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    // WriteFreely blogs have a feed at /feed/ under the blog. Instances with many blogs, like
+    // write.as, put each one under its name, E.g. /blog/ and /blog/post. Single user
+    // instances have the blog at the root and posts at /post, without the trailing slash.
+    fn guess_writefreely(&self) -> FeedResult {
+        let segments = self
+            .base_url
+            .path_segments()
+            .map(|segments| segments.collect::<Vec<_>>())
+            .unwrap_or_default();
+        // A path of /blog/ has two segments, the second empty, where /post only has one
+        let multiple = self.base_url.host_str() == Some("write.as") || segments.len() > 1;
+        let path = match segments.first() {
+            Some(blog) if multiple && !blog.is_empty() => format!("/{}/feed/", blog),
+            _ => String::from("/feed/"),
+        };
+
+        self.guess_paths(&[path])
+    }
+
+    // The content of the generator meta tags
+    fn generators(&self) -> Vec<String> {
+        self.doc
+            .select("meta[name='generator']")
+            .map(|metas| {
+                metas
+                    .filter_map(|meta| meta.attributes.borrow().get("content").map(String::from))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    // Checks the generator meta tags for one that starts with the supplied name
+    fn generated_by(&self, name: &str) -> bool {
+        self.generators()
+            .iter()
+            .any(|content| content.trim().to_lowercase().starts_with(name))
+    }
+
+    // Looks for a versioned generator meta tag from a version of the platform that used a
+    // different feed path, returning the path if found.
+    fn check_cms_versions(&self, platform: &str) -> Option<&'static str> {
+        let generators = self.generators();
+        CMS_VERSIONED_FEEDS
+            .iter()
+            .filter(|(name, _, _)| *name == platform)
+            .find(|(name, before, _)| {
+                generators.iter().any(|content| {
+                    CmsVersion::parse(content, name)
+                        .map(|version| version < *before)
+                        .unwrap_or(false)
+                })
+            })
+            .map(|&(_, _, path)| path)
+    }
+
+    // Whether the host of the page is the domain or a subdomain of it
+    fn on_domain(&self, domain: &str) -> bool {
+        self.base_url
+            .host_str()
+            .map(|host| on_domain(host, domain))
+            .unwrap_or(false)
+    }
+
+    // Checks whether the page looks like it was generated by the software of a built-in rule.
+    // This is the same as a PlatformMatch::Any of the generators, markup, and hosts, and a
+    // PlatformMatch::All of the markup_all, but without building one for each rule.
+    fn matches_rule(&self, rule: &GuessRule, markup: &str) -> bool {
+        rule.generators.iter().any(|name| self.generated_by(name))
+            || rule.markup.iter().any(|text| markup.contains(text))
+            || (!rule.markup_all.is_empty()
+                && rule.markup_all.iter().all(|text| markup.contains(text)))
+            || rule.hosts.iter().any(|domain| self.on_domain(domain))
+    }
+
+    // Checks whether the page looks like it was generated by the software of a rule
+    fn matches_platform(&self, matches: &PlatformMatch, markup: &str) -> bool {
+        match matches {
+            PlatformMatch::Generator(name) => self.generated_by(&name.to_lowercase()),
+            PlatformMatch::MarkupContains(text) => markup.contains(&text.to_lowercase()),
+            PlatformMatch::Any(matches) => matches
+                .iter()
+                .any(|matches| self.matches_platform(matches, markup)),
+            PlatformMatch::All(matches) => {
+                !matches.is_empty()
+                    && matches
+                        .iter()
+                        .all(|matches| self.matches_platform(matches, markup))
+            }
+            PlatformMatch::Domain(domain) => self.on_domain(&domain.to_lowercase()),
+        }
+    }
+
+    // Guesses the feed for some well known locations, using the first rule that matches. Rules
+    // added to the config come before the built-in ones.
+    fn guess(&self) -> FeedResult {
+        let markup = self.markup().to_lowercase();
+
+        let platform_rule = self
+            .config
+            .platform_rules
+            .iter()
+            .find(|rule| self.matches_platform(&rule.matches, &markup));
+        let mut feeds = if let Some(rule) = platform_rule {
+            debug!(rule = %rule.name, "guessing from platform rule");
+            self.guess_platform(rule)?
+        } else if let Some(rule) = rules::RULES
+            .iter()
+            .find(|rule| self.matches_rule(rule, &markup))
+        {
+            debug!(rule = rule.name, "guessing from rule");
+            self.guess_rule(rule)?
+        } else {
+            debug!("unable to identify the software used to generate the page");
+            return Ok(Vec::new());
+        };
+
+        // An app with a feed in its name, like "Example RSS Reader", is more likely to have a
+        // feed where expected. It's still only a guess, so at most Medium.
+        let feed_app = self
+            .application_name()
+            .map(|name| MIGHT_BE_FEED.iter().any(|hint| name.contains(hint)))
+            .unwrap_or(false);
+        if feed_app {
+            for feed in feeds.iter_mut() {
+                feed.confidence = match feed.confidence {
+                    Confidence::VeryLow => Confidence::Low,
+                    Confidence::Low | Confidence::Medium => Confidence::Medium,
+                    Confidence::High => Confidence::High,
+                };
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    fn guess_platform(&self, rule: &PlatformRule) -> FeedResult {
+        let mut feeds = Vec::new();
+        for feed in &rule.feeds {
+            match feed {
+                PlatformFeed::RootPath(path) => feeds.extend(self.guess_paths(&[path])?),
+                PlatformFeed::Segments(files) => {
+                    let files = files.iter().map(String::as_str).collect::<Vec<_>>();
+                    feeds.extend(self.guess_segments(&files)?)
+                }
+                PlatformFeed::Transform(transform) => {
+                    if let Some(url) = transform(self.base_url) {
+                        feeds.push(Feed::candidate(
+                            url,
+                            FeedType::Guess,
+                            Confidence::Low,
+                            FeedSource::Guess,
+                        ))
+                    }
+                }
+                PlatformFeed::UrlTemplate(template) => {
+                    if let Some(path) = fill_template(template, self.base_url) {
+                        feeds.extend(self.guess_paths(&[&path])?)
+                    }
+                }
+            }
+        }
+
+        Ok(feeds)
+    }
+
+    fn guess_rule(&self, rule: &GuessRule) -> FeedResult {
+        match rule.action {
+            GuessAction::Segments(feed_files) => self.guess_segments(feed_files),
+            GuessAction::Root(path) => self.guess_paths(&[path]),
+            GuessAction::VersionedRoot(path) => {
+                self.guess_paths(&[self.check_cms_versions(rule.name).unwrap_or(path)])
+            }
+            GuessAction::Handler(ref handler) => match handler {
+                GuessHandler::Pelican => self.guess_pelican(),
+                GuessHandler::Gatsby => self.guess_gatsby(),
+                GuessHandler::Nikola => self.guess_nikola(),
+                GuessHandler::Discourse => self.guess_discourse(),
+                GuessHandler::Phpbb => self.guess_phpbb(),
+                GuessHandler::Vbulletin => self.guess_vbulletin(),
+                GuessHandler::Mediawiki => self.guess_mediawiki(),
+                GuessHandler::Dokuwiki => self.guess_dokuwiki(),
+                GuessHandler::Mastodon => self.guess_mastodon(),
+                GuessHandler::Pleroma => self.guess_pleroma(),
+                GuessHandler::Pixelfed => self.guess_pixelfed(),
+                GuessHandler::Writefreely => self.guess_writefreely(),
+                GuessHandler::Tumblr => self.guess_tumblr(),
+                GuessHandler::Nitter => self.guess_nitter(),
+                GuessHandler::Lemmy => self.guess_lemmy(),
+                GuessHandler::Shopify => self.guess_shopify(),
+                GuessHandler::Weebly => self.guess_weebly(),
+                GuessHandler::Livejournal => self.guess_livejournal(),
+                GuessHandler::Dreamwidth => self.guess_dreamwidth(),
+                GuessHandler::Typepad => self.guess_typepad(),
+                GuessHandler::Grav => self.guess_grav(),
+                GuessHandler::Buttondown => self.guess_buttondown(),
+                GuessHandler::Mailchimp => self.guess_mailchimp(),
+                GuessHandler::Wordpress => self.guess_wordpress(),
+                GuessHandler::Hugo => self.guess_hugo(),
+                GuessHandler::Forem => self.guess_forem(),
+                GuessHandler::Medium => self.guess_medium(),
+            },
+        }
+    }
+
+    // Articles are marked up for sharing and search engines with Open Graph and schema.org
+    // types, which are checked before the hints of an app as blogs often have those too.
+    //
+    // Touch icons aren't used to find feeds: they're named for the device, not the content,
+    // so one named like apple-touch-icon-rss.png is no more likely to be a feed than any other.
+    // Alongside a web app manifest they do suggest an app rather than a document though.
+    fn site_type(&self) -> SiteType {
+        let markup = self.markup().to_lowercase();
+        let has = |selector: &str| {
+            self.doc
+                .select_first(selector)
+                .map(|_| true)
+                .unwrap_or(false)
+        };
+
+        if has("meta[name='news_keywords']") || markup.contains("\"newsarticle\"") {
+            SiteType::News
+        } else if has("meta[property='og:type'][content='article']")
+            || markup.contains("\"blogposting\"")
+        {
+            SiteType::Blog
+        } else if has("link[rel='manifest']")
+            && (has("link[rel~='apple-touch-icon']")
+                || has("meta[name='apple-mobile-web-app-capable']")
+                || has("meta[name='mobile-web-app-capable']"))
+        {
+            SiteType::App
+        } else {
+            SiteType::Unknown
+        }
+    }
+
+    // The document serialized as HTML. This is the same as self.doc.to_string() but walks the
+    // tree instead of recursing, which would overflow the stack on deeply nested documents.
+    fn markup(&self) -> String {
+        let mut markup = Vec::new();
+        let mut serializer = HtmlSerializer::new(&mut markup, SerializeOpts::default());
+        for edge in self.doc.traverse() {
+            // Writing to a Vec can't fail
+            let _ = match edge {
+                NodeEdge::Start(node) => match node.data() {
+                    NodeData::Element(element) => {
+                        let attrs = element.attributes.borrow();
+                        let names = attrs
+                            .map
+                            .iter()
+                            .map(|(name, attr)| {
+                                html5ever::QualName::new(
+                                    attr.prefix.clone(),
+                                    name.ns.clone(),
+                                    name.local.clone(),
+                                )
+                            })
+                            .collect::<Vec<_>>();
+                        serializer.start_elem(
+                            element.name.clone(),
+                            names
+                                .iter()
+                                .zip(attrs.map.values())
+                                .map(|(name, attr)| (name, attr.value.as_str())),
+                        )
+                    }
+                    NodeData::Text(text) => serializer.write_text(&text.borrow()),
+                    NodeData::Comment(text) => serializer.write_comment(&text.borrow()),
+                    NodeData::Doctype(doctype) => serializer.write_doctype(&doctype.name),
+                    NodeData::ProcessingInstruction(contents) => {
+                        let contents = contents.borrow();
+                        serializer.write_processing_instruction(&contents.0, &contents.1)
+                    }
+                    NodeData::Document(_) | NodeData::DocumentFragment => Ok(()),
+                },
+                NodeEdge::End(node) => match node.as_element() {
+                    Some(element) => serializer.end_elem(element.name.clone()),
+                    None => Ok(()),
+                },
+            };
+        }
+
+        String::from_utf8_lossy(&markup).into_owned()
+    }
+
+    // The lowercased name of the web app given by the application-name meta tag
+    fn application_name(&self) -> Option<String> {
+        self.doc
+            .select_first("meta[name='application-name']")
+            .ok()
+            .and_then(|meta| {
+                meta.attributes
+                    .borrow()
+                    .get("content")
+                    .map(|name| name.trim().to_lowercase())
+            })
+    }
+}
+
+impl FeedType {
+    /// Get the type of feed for a media type, like the `type` attribute of a `<link>` tag or
+    /// the `Content-Type` of a response.
+    ///
+    /// RSS 1.0 feeds, which are RDF documents, are served as `application/rdf+xml` and are
+    /// `Rss`. Parameters, like `charset`, are ignored. `None` is returned for media types that
+    /// aren't feeds.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// use feedfinder::FeedType;
+    ///
+    /// assert_eq!(FeedType::from_mime("application/rdf+xml"), Some(FeedType::Rss));
+    /// assert_eq!(FeedType::from_mime("text/html; charset=utf-8"), None);
+    /// ```
+    pub fn from_mime(mime: &str) -> Option<FeedType> {
+        match media_type(mime).as_str() {
+            "application/rss+xml" | "application/rdf+xml" => Some(FeedType::Rss),
+            "application/atom+xml" => Some(FeedType::Atom),
+            "application/json" => Some(FeedType::Json),
+            _ => None,
+        }
+    }
+}
+
+impl Feed {
+    /// Create a feed that is already known, to use alongside detected feeds.
+    ///
+    /// The feed has `High` confidence and the `Manual` source.
+    ///
+    /// ### Example
+    ///
+    /// ```rust
+    /// use feedfinder::{Feed, FeedType};
+    /// use url::Url;
+    ///
+    /// let url = Url::parse("https://example.com/feed.xml").unwrap();
+    /// let feed = Feed::new(url, FeedType::Atom).with_title("My Blog Feed");
+    /// assert_eq!(feed.title(), Some("My Blog Feed"));
+    /// ```
+    pub fn new(url: Url, type_: FeedType) -> Self {
+        Feed::candidate(url, type_, Confidence::High, FeedSource::Manual)
+    }
+
+    // A feed found by a source, without any of the optional details, which are added with
+    // struct update syntax, E.g. Feed { title, ..Feed::candidate(...) }
+    fn candidate(url: Url, type_: FeedType, confidence: Confidence, source: FeedSource) -> Self {
+        Feed {
+            url,
+            type_,
+            title: None,
+            confidence,
+            source,
+            hreflang: None,
+            hub: None,
+            next: None,
+            prev: None,
+        }
+    }
+
+    /// Set the title of the feed.
+    pub fn with_title<S: Into<String>>(mut self, title: S) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
     /// Get the URL of this feed.
     pub fn url(&self) -> &Url {
         &self.url
     }
 
-    /// Get the type of this feed.
-    pub fn feed_type(&self) -> &FeedType {
-        &self.type_
+    /// Get the type of this feed.
+    pub fn feed_type(&self) -> &FeedType {
+        &self.type_
+    }
+
+    /// Get the title of the feed if available.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Get how confident feedfinder is that this candidate is a feed.
+    pub fn confidence(&self) -> Confidence {
+        self.confidence
+    }
+
+    /// Get where in the content this feed was found.
+    pub fn source(&self) -> FeedSource {
+        self.source
+    }
+
+    /// Get the language of the feed if it was given by a `hreflang` attribute.
+    pub fn hreflang(&self) -> Option<&str> {
+        self.hreflang.as_deref()
+    }
+
+    /// Get the [WebSub](https://www.w3.org/TR/websub/) (formerly PubSubHubbub) hub that
+    /// publishes updates to this feed, if known without fetching it.
+    ///
+    /// Clients that support WebSub can subscribe to the hub straight away to be pushed new
+    /// entries instead of polling.
+    pub fn hub(&self) -> Option<&Url> {
+        self.hub.as_ref()
+    }
+
+    /// Get the next page of the feed if it was linked from the page with a typed
+    /// `<link rel="next">`.
+    ///
+    /// Feeds that are split into pages (RFC 5005) can be walked with this and
+    /// [prev](#method.prev) to fetch the archive.
+    pub fn next(&self) -> Option<&Url> {
+        self.next.as_ref()
+    }
+
+    /// Get the previous page of the feed if it was linked from the page with a typed
+    /// `<link rel="prev">`.
+    pub fn prev(&self) -> Option<&Url> {
+        self.prev.as_ref()
+    }
+
+    /// Get the [Podcast Index](https://podcastindex.org/) API URL to look up this feed.
+    ///
+    /// Podcasts are published as RSS (or occasionally Atom) feeds, so `None` is returned for
+    /// JSON feeds and sitemaps. The URL doesn't include any credentials: the API authenticates
+    /// requests with the `X-Auth-Key`, `X-Auth-Date`, and `Authorization` headers, which the
+    /// caller needs to add when fetching it.
+    pub fn podcast_index_url(&self) -> Option<Url> {
+        match self.type_ {
+            FeedType::Json | FeedType::Sitemap | FeedType::Opml => None,
+            _ => Url::parse_with_params(
+                "https://api.podcastindex.org/api/1.0/podcasts/byfeedurl",
+                &[("url", self.url.as_str())],
+            )
+            .ok(),
+        }
+    }
+}
+
+impl FeedFinderConfig {
+    /// Create a new config with the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the path of the root of the site.
+    ///
+    /// Guesses for feeds that are found at the root of a site, like `/feed` for WordPress,
+    /// are resolved against this path instead of `/`. This is useful for sites served under a
+    /// path prefix, E.g. a WordPress install at the root of a server that is proxied to
+    /// `https://example.com/blog/`.
+    pub fn with_site_root<S: Into<String>>(mut self, path: S) -> Self {
+        self.site_root = Some(path.into());
+        self
+    }
+
+    /// Enable detection of paged feeds.
+    ///
+    /// Feeds split into pages link to the other pages with `<link rel="first">`,
+    /// `<link rel="next">`, and `<link rel="last">` (RFC 5005). When enabled these links are
+    /// returned with the `Paged` source when the page has no `<link rel="alternate">` feeds,
+    /// indicating that the pages need to be followed to get all the entries. Disabled by
+    /// default as pages are rarely what's wanted when subscribing.
+    pub fn with_paged_feeds(mut self, enabled: bool) -> Self {
+        self.paged_feeds = enabled;
+        self
+    }
+
+    /// Enable recognition of subscribe widgets.
+    ///
+    /// Sites often list their feeds in a section headed "Subscribe", "Feeds", or "RSS". When
+    /// enabled, links that look like feeds within such a section are returned with `High`
+    /// confidence ahead of other links in the body. Disabled by default as the heading is only
+    /// a hint.
+    pub fn with_subscribe_widgets(mut self, enabled: bool) -> Self {
+        self.subscribe_widgets = enabled;
+        self
+    }
+
+    /// Enable returning the feeds listed in OPML content.
+    ///
+    /// When the content is OPML, like a blogroll or an export of subscriptions, it's returned
+    /// as a candidate of type `Opml`. When enabled the feeds it lists are returned after it with
+    /// the `Opml` source, which is useful for importing them. Disabled by default as the feeds
+    /// belong to other sites.
+    pub fn with_opml_outlines(mut self, enabled: bool) -> Self {
+        self.opml_outlines = enabled;
+        self
+    }
+
+    /// Add a rule for guessing the feeds of a platform.
+    ///
+    /// Rules added this way are tried in the order they're added, before the built-in rules,
+    /// so they can also change the guesses for a platform that is already supported.
+    pub fn with_platform_rule(mut self, rule: PlatformRule) -> Self {
+        self.platform_rules.push(rule);
+        self
+    }
+
+    /// Limit the number of elements examined when looking for `<link>` and `<a>` tags.
+    ///
+    /// This bounds the time taken on pages with a huge number of elements, at the cost of
+    /// missing feeds linked after the limit is reached. There is no limit by default.
+    pub fn with_scan_limit(mut self, elements: usize) -> Self {
+        self.scan_limit = Some(elements);
+        self
+    }
+
+    /// Limit the number of candidates returned.
+    ///
+    /// Sources are tried in order until one finds candidates, so later sources aren't tried
+    /// once the first result is found. Sources that rank their candidates, like those for
+    /// `<link>` and `<a>` tags, still scan the whole page so the best are returned, E.g. the
+    /// `<link rel="self">` feed of a page. The others stop as soon as they have found enough.
+    /// A limit of 1 is useful when only the single best feed is wanted. There is no limit by
+    /// default.
+    pub fn with_max_results(mut self, results: usize) -> Self {
+        self.max_results = Some(results);
+        self
+    }
+}
+
+impl FeedFinderReport {
+    /// Get the feeds that [detect_feeds](fn.detect_feeds.html) would return.
+    ///
+    /// These are the candidates of the first source that found any. Sources that failed are
+    /// skipped rather than ending detection. There are none if the page redirects, like
+    /// `detect_feeds`, which returns an error instead.
+    pub fn feeds(&self) -> &[Feed] {
+        if self.redirect.is_some() {
+            return &[];
+        }
+
+        self.sources
+            .iter()
+            .map(|source| source.candidates.as_slice())
+            .find(|candidates| !candidates.is_empty())
+            .unwrap_or(&[])
+    }
+
+    /// Get the report for each source, in the order they're tried.
+    pub fn sources(&self) -> &[SourceReport] {
+        &self.sources
+    }
+
+    /// Get the errors encountered, along with the source that produced them.
+    pub fn errors(&self) -> impl Iterator<Item = (FeedSource, &FeedFinderError)> {
+        self.sources
+            .iter()
+            .filter_map(|source| source.error.as_ref().map(|err| (source.source, err)))
+    }
+
+    /// Get the URL the page redirects to with `<meta http-equiv="refresh">`, if any.
+    ///
+    /// This is the URL of the [Redirect](enum.FeedFinderError.html#variant.Redirect) error
+    /// that [detect_feeds](fn.detect_feeds.html) would return. The sources are still run so
+    /// their reports are available.
+    pub fn redirect(&self) -> Option<&Url> {
+        self.redirect.as_ref()
+    }
+
+    /// Get the title of the page if it has one.
+    ///
+    /// This is useful as a name for feeds that don't have a title of their own, like those
+    /// linked via a `<link>` tag without a `title` attribute.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Get the messages for problems the HTML parser recovered from.
+    ///
+    /// The parser never fails, instead it repairs malformed HTML the way a browser would.
+    /// Warnings indicate the page was broken in some way and the repaired document may not be
+    /// what the author intended.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    /// Get the total time taken, including parsing the HTML.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+impl SiteMetadata {
+    /// Get the title of the page if it has one.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+
+    /// Get the URL of the icon of the site if the page links to one.
+    ///
+    /// A favicon linked via `<link rel="icon">` is preferred, otherwise the icon linked via
+    /// `<link rel="apple-touch-icon">` is used.
+    pub fn icon(&self) -> Option<&Url> {
+        self.icon.as_ref()
+    }
+
+    /// Get the feeds that [detect_feeds](fn.detect_feeds.html) would return.
+    pub fn feeds(&self) -> &[Feed] {
+        &self.feeds
+    }
+}
+
+impl SourceReport {
+    /// Get the source this report is for.
+    ///
+    /// Sources can produce feeds with a more specific source, such as `MetaLink` producing
+    /// `Comments` feeds.
+    pub fn source(&self) -> FeedSource {
+        self.source
+    }
+
+    /// Get all of the candidates this source found.
+    pub fn candidates(&self) -> &[Feed] {
+        &self.candidates
+    }
+
+    /// Get the error that stopped this source, if any.
+    pub fn error(&self) -> Option<&FeedFinderError> {
+        self.error.as_ref()
+    }
+
+    /// Get the time taken by this source.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+}
+
+impl fmt::Display for FeedFinderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FeedFinderError::Url(err) => err.fmt(f),
+            FeedFinderError::Select => f.write_str("unable to select elements in doc"),
+            FeedFinderError::Json(err) => write!(f, "unable to parse JSON: {}", err),
+            FeedFinderError::Redirect(url) => write!(f, "page redirects to {}", url),
+        }
+    }
+}
+
+impl std::error::Error for FeedFinderError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FeedFinderError::Url(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(url: &str, type_: FeedType, confidence: Confidence, source: FeedSource) -> Feed {
+        Feed::candidate(Url::parse(url).unwrap(), type_, confidence, source)
+    }
+
+    fn guess(url: &str) -> Feed {
+        feed(url, FeedType::Guess, Confidence::Low, FeedSource::Guess)
+    }
+
+    #[test]
+    fn test_send_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<Feed>();
+        assert_send_sync::<FeedType>();
+        assert_send_sync::<Confidence>();
+        assert_send_sync::<FeedSource>();
+        assert_send_sync::<FeedFinderError>();
+        assert_send_sync::<SiteType>();
+        assert_send_sync::<FeedFinderConfig>();
+        assert_send_sync::<PlatformRule>();
+        assert_send_sync::<PlatformMatch>();
+        assert_send_sync::<PlatformFeed>();
+        assert_send_sync::<FeedFinderReport>();
+        assert_send_sync::<SourceReport>();
+        assert_send_sync::<SiteMetadata>();
+    }
+
+    #[test]
+    fn test_meta_refresh() {
+        let base = Url::parse("http://example.com/old-location/").unwrap();
+        let html = r#"<html><head><meta http-equiv="refresh" content="0; url=/new-location/"></head><body>Moved</body></html>"#;
+        let url = Url::parse("http://example.com/new-location/").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Err(FeedFinderError::Redirect(url))
+        );
+    }
+
+    #[test]
+    fn test_meta_refresh_report() {
+        let base = Url::parse("http://example.com/old-location/").unwrap();
+        let html = r#"<html><head><meta http-equiv="refresh" content="0; url=/new-location/"><link rel="alternate" type="application/rss+xml" href="/feed.rss"></head><body>Moved</body></html>"#;
+        let report = detect_feeds_report(&base, html);
+        assert_eq!(
+            report.redirect(),
+            Some(&Url::parse("http://example.com/new-location/").unwrap())
+        );
+        assert_eq!(report.feeds(), &[]);
+        assert_eq!(report.sources()[1].candidates().len(), 1);
+    }
+
+    #[test]
+    fn test_meta_refresh_fragment() {
+        let base = Url::parse("http://example.com/old-location/").unwrap();
+        let html = r#"<meta http-equiv="refresh" content="0; url=/new-location/"><a href="/feed/">RSS</a>"#;
+        let url = Url::parse("http://example.com/new-location/").unwrap();
+        assert_eq!(
+            detect_feeds_fragment(&base, html),
+            Err(FeedFinderError::Redirect(url))
+        );
+    }
+
+    #[test]
+    fn test_meta_refresh_quoted() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta http-equiv="Refresh" content="5;URL='https://example.org/'"></head></html>"#;
+        let url = Url::parse("https://example.org/").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Err(FeedFinderError::Redirect(url))
+        );
+    }
+
+    #[test]
+    fn test_meta_refresh_reload() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta http-equiv="refresh" content="300"><link rel="alternate" type="application/rss+xml" href="/feed.rss"></head></html>"#;
+        assert_eq!(detect_feeds(&base, html).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_detect_meta_atom() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="alternate" type="application/atom+xml" href="http://example.com/feed.atom"></head></html>"#;
+        let url = Url::parse("http://example.com/feed.atom").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Atom,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_rss() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="alternate" type="application/rss+xml" href="http://example.com/feed.rss"></head></html>"#;
+        let url = Url::parse("http://example.com/feed.rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Rss,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_rss_title() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="alternate" type="application/rss+xml" href="http://example.com/feed.rss" title="RSS Feed"></head></html>"#;
+        let url = Url::parse("http://example.com/feed.rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some(String::from("RSS Feed")),
+                ..Feed::candidate(url, FeedType::Rss, Confidence::High, FeedSource::MetaLink)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_rss_title_multiple() {
+        let base = Url::parse("https://wordpress.com/blog/2021/12/07/drive-more-traffic-to-your-site-with-a-link-in-bio-social-links-page/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/rss+xml" title="WordPress.com Blog" href="https://wordpress.com/blog/feed/">
+        <link rel="alternate" type="application/rss+xml" title="WordPress.com News » Drive More Traffic To Your Site With a “Link In Bio” Social Links&nbsp;Page Comments Feed" href="https://wordpress.com/blog/2021/12/07/drive-more-traffic-to-your-site-with-a-link-in-bio-social-links-page/feed/">
+        </head></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed { title: Some(String::from("WordPress.com Blog")), ..Feed::candidate("https://wordpress.com/blog/feed/".parse().unwrap(), FeedType::Rss, Confidence::High, FeedSource::MetaLink) },
+            Feed { title: Some(String::from("WordPress.com News » Drive More Traffic To Your Site With a “Link In Bio” Social Links\u{a0}Page Comments Feed")), ..Feed::candidate("https://wordpress.com/blog/2021/12/07/drive-more-traffic-to-your-site-with-a-link-in-bio-social-links-page/feed/".parse().unwrap(), FeedType::Rss, Confidence::High, FeedSource::MetaLink) },])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_rss_relative() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="alternate" type="application/rss+xml" href="/feed.rss"></head></html>"#;
+        let url = Url::parse("http://example.com/feed.rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Rss,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_json_feed() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="alternate" type="application/json" href="http://example.com/feed.json"></head></html>"#;
+        let url = Url::parse("http://example.com/feed.json").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Json,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_hreflang() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/rss+xml" href="/feed.rss">
+        <link rel="alternate" hreflang="fr" type="application/rss+xml" href="/fr/feed.rss">
+        </head></html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| (feed.url().as_str(), feed.hreflang()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("http://example.com/feed.rss", None),
+                ("http://example.com/fr/feed.rss", Some("fr")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_type_parameters() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/rss+xml; q=0.9" href="/feed.rss">
+        <link rel="alternate" type=" Application/Atom+XML ;charset=utf-8" href="/feed.atom">
+        </head></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                feed(
+                    "http://example.com/feed.rss",
+                    FeedType::Rss,
+                    Confidence::High,
+                    FeedSource::MetaLink
+                ),
+                feed(
+                    "http://example.com/feed.atom",
+                    FeedType::Atom,
+                    Confidence::High,
+                    FeedSource::MetaLink
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_self() {
+        let base = Url::parse("http://example.com/hub/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/rss+xml" href="/hub/feed.rss">
+        <link rel="self" type="application/atom+xml" href="/hub/feed.atom">
+        </head></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                feed(
+                    "http://example.com/hub/feed.atom",
+                    FeedType::Atom,
+                    Confidence::High,
+                    FeedSource::SelfLink
+                ),
+                feed(
+                    "http://example.com/hub/feed.rss",
+                    FeedType::Rss,
+                    Confidence::High,
+                    FeedSource::MetaLink
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_media() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate stylesheet" media="print" type="text/css" href="/print.css">
+        <link rel="alternate" media="handheld" type="application/rss+xml" href="/feed.rss">
+        </head></html>"#;
+        let url = Url::parse("http://example.com/feed.rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Rss,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_paged() {
+        let base = Url::parse("http://example.com/archive/2/").unwrap();
+        let html = r#"<html><head>
+        <link rel="next" href="/archive/3/">
+        <link rel="first" type="application/atom+xml" href="/feed.atom">
+        <link rel="next" type="application/atom+xml" href="/feed.atom?page=3">
+        </head></html>"#;
+        assert_eq!(detect_feeds(&base, html), Ok(vec![]));
+
+        let config = FeedFinderConfig::new().with_paged_feeds(true);
+        let feeds = detect_feeds_with_config(&base, html, &config).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| (feed.url().as_str(), feed.source()))
+                .collect::<Vec<_>>(),
+            vec![
+                ("http://example.com/feed.atom", FeedSource::Paged),
+                ("http://example.com/feed.atom?page=3", FeedSource::Paged),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_detect_paged_rel_tokens() {
+        let base = Url::parse("http://example.com/archive/2/").unwrap();
+        let html = r#"<html><head>
+        <link rel="First prefetch" type="application/atom+xml; charset=utf-8" href="/feed.atom">
+        <link rel="preload" type="application/atom+xml" href="/style.atom">
+        </head></html>"#;
+        let config = FeedFinderConfig::new().with_paged_feeds(true);
+        let feeds = detect_feeds_with_config(&base, html, &config).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| (feed.url().as_str(), feed.feed_type(), feed.source()))
+                .collect::<Vec<_>>(),
+            vec![(
+                "http://example.com/feed.atom",
+                &FeedType::Atom,
+                FeedSource::Paged
+            )]
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_pages() {
+        let base = Url::parse("http://example.com/archive/2/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/atom+xml" href="/feed.atom">
+        <link rel="next" href="/archive/3/">
+        <link rel="next" type="application/atom+xml" href="/feed.atom?page=3">
+        <link rel="prev" type="application/atom+xml" href="/feed.atom?page=1">
+        </head></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                next: Some(Url::parse("http://example.com/feed.atom?page=3").unwrap()),
+                prev: Some(Url::parse("http://example.com/feed.atom?page=1").unwrap()),
+                ..feed(
+                    "http://example.com/feed.atom",
+                    FeedType::Atom,
+                    Confidence::High,
+                    FeedSource::MetaLink
+                )
+            },])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_rdf() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="alternate" type="application/rdf+xml" href="/index.rdf"></head></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![feed(
+                "http://example.com/index.rdf",
+                FeedType::Rss,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_feed_type_from_mime() {
+        assert_eq!(
+            FeedType::from_mime("application/rss+xml"),
+            Some(FeedType::Rss)
+        );
+        assert_eq!(
+            FeedType::from_mime("Application/RDF+XML; charset=utf-8"),
+            Some(FeedType::Rss)
+        );
+        assert_eq!(
+            FeedType::from_mime("application/atom+xml"),
+            Some(FeedType::Atom)
+        );
+        assert_eq!(
+            FeedType::from_mime("application/json"),
+            Some(FeedType::Json)
+        );
+        assert_eq!(FeedType::from_mime("application/xml"), None);
+    }
+
+    #[test]
+    fn test_detect_meta_template() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head></head><body>
+        <template id="site-meta"><link rel="alternate" type="application/rss+xml" href="/feed.rss"></template>
+        </body></html>"#;
+        let url = Url::parse("http://example.com/feed.rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Rss,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_replies() {
+        let base = Url::parse("http://example.com/2021/first-post/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/atom+xml" href="/feed.atom">
+        <link rel="replies" type="application/atom+xml" href="/2021/first-post/comments.atom" title="Comments">
+        </head></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                feed(
+                    "http://example.com/feed.atom",
+                    FeedType::Atom,
+                    Confidence::High,
+                    FeedSource::MetaLink
+                ),
+                Feed {
+                    title: Some(String::from("Comments")),
+                    ..feed(
+                        "http://example.com/2021/first-post/comments.atom",
+                        FeedType::Atom,
+                        Confidence::High,
+                        FeedSource::Comments
+                    )
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_replies_only() {
+        let base = Url::parse("http://example.com/2021/first-post/").unwrap();
+        let html = r#"<html><head><link rel="replies" type="application/atom+xml" href="comments.atom"></head></html>"#;
+        let url = Url::parse("http://example.com/2021/first-post/comments.atom").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Atom,
+                Confidence::High,
+                FeedSource::Comments
+            ),])
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_detect_feeds_stream() {
+        use futures_util::StreamExt;
+
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/rss+xml" href="/feed.rss">
+        <link rel="alternate" type="application/atom+xml" href="/feed.atom">
+        </head></html>"#;
+        let feeds =
+            futures_executor::block_on(detect_feeds_stream(&base, html).collect::<Vec<_>>());
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| feed.as_ref().map(|feed| feed.url().as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                Ok("http://example.com/feed.rss"),
+                Ok("http://example.com/feed.atom")
+            ]
+        );
+
+        let html = r#"<meta http-equiv="refresh" content="0; url=/moved/">"#;
+        let feeds =
+            futures_executor::block_on(detect_feeds_stream(&base, html).collect::<Vec<_>>());
+        assert_eq!(
+            feeds,
+            vec![Err(FeedFinderError::Redirect(
+                Url::parse("http://example.com/moved/").unwrap()
+            ))]
+        );
+    }
+
+    #[test]
+    fn test_filter_by_type() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/rss+xml" href="/feed.rss">
+        <link rel="alternate" type="application/json" href="/feed.json">
+        <link rel="alternate" type="application/atom+xml" href="/feed.atom">
+        </head></html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            filter_by_type(feeds, &[FeedType::Json]),
+            vec![feed(
+                "http://example.com/feed.json",
+                FeedType::Json,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),]
+        );
+    }
+
+    #[test]
+    fn test_podcast_index_url() {
+        let feed = feed(
+            "https://example.com/podcast.rss?format=mp3",
+            FeedType::Rss,
+            Confidence::High,
+            FeedSource::MetaLink,
+        );
+        assert_eq!(
+            feed.podcast_index_url(),
+            Some(Url::parse("https://api.podcastindex.org/api/1.0/podcasts/byfeedurl?url=https%3A%2F%2Fexample.com%2Fpodcast.rss%3Fformat%3Dmp3").unwrap())
+        );
+    }
+
+    #[test]
+    fn test_podcast_index_url_json() {
+        let feed = feed(
+            "https://example.com/feed.json",
+            FeedType::Json,
+            Confidence::High,
+            FeedSource::MetaLink,
+        );
+        assert_eq!(feed.podcast_index_url(), None);
+    }
+
+    #[test]
+    fn test_detect_feeds_from_oembed() {
+        let base = Url::parse(
+            "https://www.youtube.com/oembed?url=https://www.youtube.com/watch?v=0gjFYpvHyrY",
+        )
+        .unwrap();
+        let json = r#"{
+            "title": "Example",
+            "author_name": "Example",
+            "author_url": "https://www.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA",
+            "type": "video",
+            "provider_name": "YouTube",
+            "provider_url": "https://www.youtube.com/"
+        }"#;
+        let url = Url::parse(
+            "https://www.youtube.com/feeds/videos.xml?channel_id=UCaYhcUwRBNscFNUKTjgPFiA",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_feeds_from_oembed(&base, json),
+            Ok(vec![Feed {
+                hub: Some(Url::parse("https://pubsubhubbub.appspot.com/").unwrap()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_detect_feeds_from_oembed_author_and_provider() {
+        let base = Url::parse("https://example.github.io/oembed.json").unwrap();
+        let json = r#"{
+            "type": "rich",
+            "author_url": "/blog/",
+            "provider_url": "https://example.github.io/"
+        }"#;
+        assert_eq!(
+            detect_feeds_from_oembed(&base, json),
+            Ok(vec![
+                guess("https://example.github.io/feed.xml"),
+                guess("https://example.github.io/atom.xml"),
+                guess("https://example.github.io/blog/feed.xml"),
+                guess("https://example.github.io/blog/atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_detect_feeds_from_oembed_invalid() {
+        let base = Url::parse("https://example.com/oembed").unwrap();
+        assert!(matches!(
+            detect_feeds_from_oembed(&base, "<html>"),
+            Err(FeedFinderError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_detect_feeds_grouped() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/rss+xml" href="/feed.rss">
+        <link rel="alternate" type="application/json" href="/feed.json">
+        <link rel="alternate" type="application/rss+xml" href="/comments.rss">
+        </head></html>"#;
+        let mut expected = HashMap::new();
+        expected.insert(
+            FeedType::Rss,
+            vec![
+                feed(
+                    "http://example.com/feed.rss",
+                    FeedType::Rss,
+                    Confidence::High,
+                    FeedSource::MetaLink,
+                ),
+                feed(
+                    "http://example.com/comments.rss",
+                    FeedType::Rss,
+                    Confidence::High,
+                    FeedSource::MetaLink,
+                ),
+            ],
+        );
+        expected.insert(
+            FeedType::Json,
+            vec![feed(
+                "http://example.com/feed.json",
+                FeedType::Json,
+                Confidence::High,
+                FeedSource::MetaLink,
+            )],
+        );
+        assert_eq!(detect_feeds_grouped(&base, html), Ok(expected));
+    }
+
+    #[test]
+    fn test_detect_feeds_report() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.2"></head>
+        <body><a href="/feed/">RSS</a><a href="http://[::1/comments.rss">Comments</a></body></html>"#;
+        let report = detect_feeds_report(&base, html);
+
+        let sources = report
+            .sources()
+            .iter()
+            .map(|source| (source.source(), source.candidates().len()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            sources,
+            vec![
+                (FeedSource::Content, 0),
+                (FeedSource::MetaLink, 0),
+                (FeedSource::Paged, 0),
+                (FeedSource::YouTube, 0),
+                (FeedSource::Reddit, 0),
+                (FeedSource::StackExchange, 0),
+                (FeedSource::GitHub, 0),
+                (FeedSource::GitLab, 0),
+                (FeedSource::Gitea, 0),
+                (FeedSource::ThirdParty, 0),
+                (FeedSource::ServiceDocument, 0),
+                (FeedSource::BodyLink, 0),
+                (FeedSource::HtmlComment, 0),
+                (FeedSource::NextData, 0),
+                (FeedSource::SchemaOrg, 0),
+                (FeedSource::Form, 0),
+                (FeedSource::Guess, 1),
+                (FeedSource::Rsd, 0),
+                (FeedSource::WellKnown, 0),
+                (FeedSource::Sitemap, 0),
+            ]
+        );
+        assert_eq!(
+            report.errors().collect::<Vec<_>>(),
+            vec![(
+                FeedSource::BodyLink,
+                &FeedFinderError::Url(url::ParseError::InvalidIpv6Address)
+            )]
+        );
+        assert_eq!(report.feeds(), &[guess("http://example.com/feed")]);
+        assert!(report.elapsed() >= report.sources()[16].elapsed());
+    }
+
+    #[test]
+    fn test_detect_feeds_report_warnings() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<!DOCTYPE html><html><head><title>Example</title></head><body><p>Fine</p></body></html>"#;
+        assert_eq!(
+            detect_feeds_report(&base, html).warnings(),
+            &[] as &[String]
+        );
+
+        let html = r#"<!DOCTYPE html><html><head><title>Example</title></head><body><b><i>Misnested</b></i></p></div></html>"#;
+        assert!(!detect_feeds_report(&base, html).warnings().is_empty());
+    }
+
+    #[test]
+    fn test_detect_atom_service_document() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="service" type="application/atomsvc+xml" href="/app/service"></head></html>"#;
+        let url = Url::parse("http://example.com/app/service").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Atom,
+                Confidence::Low,
+                FeedSource::ServiceDocument
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_atom_service_document_rel_tokens() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="Service edit" type="application/atomsvc+xml; charset=utf-8" href="/app/service"></head></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![feed(
+                "http://example.com/app/service",
+                FeedType::Atom,
+                Confidence::Low,
+                FeedSource::ServiceDocument
+            )])
+        );
+    }
+
+    #[test]
+    fn test_detect_meta_preferred_over_atom_service_document() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head>
+        <link rel="service" type="application/atomsvc+xml" href="/app/service">
+        <link rel="alternate" type="application/atom+xml" href="/feed.atom">
+        </head></html>"#;
+        let url = Url::parse("http://example.com/feed.atom").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Atom,
+                Confidence::High,
+                FeedSource::MetaLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_rsd_hint() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="EditURI" type="application/rsd+xml" title="RSD" href="http://example.com/xmlrpc.php?rsd"></head><body>Home</body></html>"#;
+        let url = Url::parse("http://example.com/xmlrpc.php?rsd").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some("RSD".to_string()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::Low, FeedSource::Rsd)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_rsd_hint_after_guess() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.4.2"><link rel="EditURI" type="application/rsd+xml" title="RSD" href="http://example.com/xmlrpc.php?rsd"></head><body>Home</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("http://example.com/feed")])
+        );
+    }
+
+    #[test]
+    fn test_link_sitemap() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="sitemap" type="application/xml" title="Sitemap" href="/sitemap.xml"></head><body>Home</body></html>"#;
+        let url = Url::parse("http://example.com/sitemap.xml").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some("Sitemap".to_string()),
+                ..Feed::candidate(
+                    url,
+                    FeedType::Sitemap,
+                    Confidence::VeryLow,
+                    FeedSource::Sitemap
+                )
+            },])
+        );
+    }
+
+    #[test]
+    fn test_link_sitemap_after_feeds() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="sitemap" type="application/xml" href="/sitemap.xml"></head><body><a href="/feed.xml">Subscribe</a></body></html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(feeds.len(), 1);
+        assert_eq!(feeds[0].source(), FeedSource::BodyLink);
+    }
+
+    #[test]
+    fn test_body_link_order() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body>
+        <nav><a href="/rss/"><img src="/rss-icon.png" alt=""></a></nav>
+        <a href="/feeds/">All feeds</a>
+        <a href="/feed/">RSS</a>
+        <a href="/feed.xml">Subscribe</a>
+        <a href="/index.atom">Atom</a>
+        </body></html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| feed.url().path())
+                .collect::<Vec<_>>(),
+            vec!["/index.atom", "/feed.xml", "/feed/", "/rss/", "/feeds/"]
+        );
+    }
+
+    #[test]
+    fn test_scan_limit() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let mut html = String::from("<html><head><title>Huge</title></head><body>");
+        for i in 0..20_000 {
+            html.push_str(&format!("<div><span>{}</span></div>", i));
+        }
+        html.push_str(r#"<link rel="alternate" type="application/rss+xml" href="/feed.rss">"#);
+        html.push_str(r#"<a href="/feed.xml">Subscribe</a></body></html>"#);
+
+        let feeds = detect_feeds(&base, &html).unwrap();
+        assert_eq!(feeds[0].source(), FeedSource::MetaLink);
+
+        let config = FeedFinderConfig::new().with_scan_limit(1000);
+        assert_eq!(detect_feeds_with_config(&base, &html, &config), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_scan_limit_templates() {
+        // The limit is shared by the document and the content of its templates, so the link
+        // isn't reached after the seven elements of the document
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><template><link rel="alternate" type="application/rss+xml" href="/feed.rss"></template></head><body><div></div><div></div><div></div></body></html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(feeds[0].source(), FeedSource::MetaLink);
+
+        let config = FeedFinderConfig::new().with_scan_limit(5);
+        assert_eq!(detect_feeds_with_config(&base, html, &config), Ok(vec![]));
+        let config = FeedFinderConfig::new().with_scan_limit(8);
+        assert_eq!(
+            detect_feeds_with_config(&base, html, &config).unwrap()[0].source(),
+            FeedSource::MetaLink
+        );
+    }
+
+    #[test]
+    fn test_max_results() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head>
+        <link rel="alternate" type="application/rss+xml" href="/feed.rss">
+        <link rel="alternate" type="application/atom+xml" href="/feed.atom">
+        <link rel="self" type="application/atom+xml" href="/posts.atom">
+        </head></html>"#;
+        assert_eq!(detect_feeds(&base, html).unwrap().len(), 3);
+
+        let config = FeedFinderConfig::new().with_max_results(1);
+        let feeds = detect_feeds_with_config(&base, html, &config).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| (feed.url().as_str(), feed.source()))
+                .collect::<Vec<_>>(),
+            vec![("http://example.com/posts.atom", FeedSource::SelfLink)]
+        );
+    }
+
+    #[test]
+    fn test_max_results_stops_scan() {
+        // The invalid form after the first fails detection if it's reached
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body><form action="/generate-rss"></form><form action="http://[::1/generate-rss"></form></body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Err(FeedFinderError::Url(url::ParseError::InvalidIpv6Address))
+        );
+
+        let config = FeedFinderConfig::new().with_max_results(1);
+        assert_eq!(
+            detect_feeds_with_config(&base, html, &config),
+            Ok(vec![feed(
+                "http://example.com/generate-rss",
+                FeedType::Link,
+                Confidence::Low,
+                FeedSource::Form
+            )])
+        );
+    }
+
+    #[test]
+    fn test_body_link_feed() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body><a href="/feed/">RSS</a></body</html>"#;
+        let url = Url::parse("http://example.com/feed/").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Medium,
+                FeedSource::BodyLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_body_link_xml() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body><a href="/index.xml">RSS</a></body</html>"#;
+        let url = Url::parse("http://example.com/index.xml").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Medium,
+                FeedSource::BodyLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_body_link_rss() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body><a href="/comments.rss">RSS</a></body</html>"#;
+        let url = Url::parse("http://example.com/comments.rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Medium,
+                FeedSource::BodyLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_body_link_atom() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html =
+            r#"<html><body><a href="http://other.example.com/posts.atom">RSS</a></body</html>"#;
+        let url = Url::parse("http://other.example.com/posts.atom").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Medium,
+                FeedSource::BodyLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_body_link_schemeless_host() {
+        let base = Url::parse("https://example.com/blog/").unwrap();
+        let html = r#"<html><body>
+        <a href="www.example.com/feed.rss">RSS</a>
+        <a href="example.com/comments.rss">Comments</a>
+        </body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                feed(
+                    "https://www.example.com/feed.rss",
+                    FeedType::Link,
+                    Confidence::Medium,
+                    FeedSource::BodyLink
+                ),
+                feed(
+                    "https://example.com/comments.rss",
+                    FeedType::Link,
+                    Confidence::Medium,
+                    FeedSource::BodyLink
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_body_link_self() {
+        let base = Url::parse("http://example.com/posts?ref=feed").unwrap();
+        let html = r##"<html><body>
+        <a href="?ref=feed">This page</a>
+        <a href="#feedback">Feedback</a>
+        <a href="/feed/">RSS</a>
+        </body</html>"##;
+        let url = Url::parse("http://example.com/feed/").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Medium,
+                FeedSource::BodyLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_body_link_feed_icon() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body>
+        <a href="/about">About</a>
+        <a href="/subscribe"><img src="/images/rss-icon.png"></a>
+        </body</html>"#;
+        let url = Url::parse("http://example.com/subscribe").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Medium,
+                FeedSource::BodyLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_body_link_feed_icon_svg() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body><a href="/subscribe"><svg class="icon icon-rss" viewBox="0 0 8 8"><circle cx="1" cy="7" r="1"/></svg></a></body</html>"#;
+        let url = Url::parse("http://example.com/subscribe").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Medium,
+                FeedSource::BodyLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_feeds_fragment() {
+        let base = Url::parse("http://example.com/about/").unwrap();
+        let html = r#"<a href="/feed">Subscribe</a>"#;
+        let url = Url::parse("http://example.com/feed").unwrap();
+        assert_eq!(
+            detect_feeds_fragment(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Medium,
+                FeedSource::BodyLink
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_detect_feeds_fragment_no_guess() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<p>Made with WordPress</p>"#;
+        assert_eq!(detect_feeds_fragment(&base, html), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_comment_links() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><!-- feed: /rss.xml --><!--[if lt IE 9]><script src="/js/html5shiv.js"></script><![endif]--></head>
+        <body>Home<!-- feedUrl: https://example.com/feed.json, generated by /bin/site --></body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                feed(
+                    "http://example.com/rss.xml",
+                    FeedType::Guess,
+                    Confidence::VeryLow,
+                    FeedSource::HtmlComment
+                ),
+                feed(
+                    "https://example.com/feed.json",
+                    FeedType::Guess,
+                    Confidence::VeryLow,
+                    FeedSource::HtmlComment
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_next_data() {
+        let base = Url::parse("https://example.com/blog/").unwrap();
+        let html = r#"<html><head><script src="/_next/static/chunks/pages/feed-3f2a.js"></script></head>
+        <body><div id="__next">Posts</div><script id="__NEXT_DATA__" type="application/json">
+        {"props":{"pageProps":{"title":"Posts","rssUrl":"/rss.xml","feeds":[{"href":"https://example.com/atom.xml"}]}},
+        "page":"/blog","buildId":"abc","scriptLoader":["/_next/static/chunks/feed.js"]}
+        </script></body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                feed(
+                    "https://example.com/atom.xml",
+                    FeedType::Guess,
+                    Confidence::Low,
+                    FeedSource::NextData
+                ),
+                feed(
+                    "https://example.com/rss.xml",
+                    FeedType::Guess,
+                    Confidence::Low,
+                    FeedSource::NextData
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_next_data_invalid() {
+        let base = Url::parse("https://example.com/").unwrap();
+        let html = r#"<html><body><script id="__NEXT_DATA__" type="application/json">{"props": /rss.xml</script></body></html>"#;
+        assert_eq!(detect_feeds(&base, html), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_schema_org() {
+        let base = Url::parse("https://example.com/").unwrap();
+        let html = r#"<html><head><script type="application/ld+json">
+        {"@context":"https://schema.org","@graph":[
+          {"@type":"WebSite","url":"https://example.com/","potentialAction":{"@type":"SearchAction","target":"https://example.com/search?feed={q}"}},
+          {"@type":"Blog","url":"https://example.com/","hasPart":{"@type":"DataFeed","url":"/feed.xml"},
+           "potentialAction":{"@type":"SubscribeAction","target":{"@type":"EntryPoint","urlTemplate":"https://example.com/rss"}}}
+        ]}
+        </script></head><body>Posts</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                feed(
+                    "https://example.com/feed.xml",
+                    FeedType::Guess,
+                    Confidence::Low,
+                    FeedSource::SchemaOrg
+                ),
+                feed(
+                    "https://example.com/rss",
+                    FeedType::Guess,
+                    Confidence::Low,
+                    FeedSource::SchemaOrg
+                ),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_schema_org_no_feed() {
+        let base = Url::parse("https://example.com/2024/first-post/").unwrap();
+        let html = r#"<html><head><script type="application/ld+json">
+        {"@context":"https://schema.org","@type":["BlogPosting"],"url":"https://example.com/2024/first-post/","headline":"First post"}
+        </script></head><body>First post!</body></html>"#;
+        assert_eq!(detect_feeds(&base, html), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_detect_feeds_deeply_nested() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = format!(
+            r#"<html><head><meta name="generator" content="WordPress 6.4"></head><body>{}First post!{}</body></html>"#,
+            "<div>".repeat(5000),
+            "</div>".repeat(5000)
+        );
+        assert_eq!(
+            detect_feeds(&base, &html),
+            Ok(vec![guess("http://example.com/feed")])
+        );
+        assert_eq!(detect_site_type(&base, &html), SiteType::Unknown);
+    }
+
+    #[test]
+    fn test_guess_tumblr() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link href="http://static.tumblr.com/example/jquery.fancybox-1.3.4.css" rel="stylesheet" type="text/css"></head><body>First post!</body</html>"#;
+        let url = Url::parse("http://example.com/rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Guess,
+                Confidence::Low,
+                FeedSource::Guess
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress.com" /></head><body>First post!</body</html>"#;
+        let url = Url::parse("http://example.com/feed").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Guess,
+                Confidence::Low,
+                FeedSource::Guess
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_guess_hugo() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Hugo 0.27.1" /></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/index.xml"),
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/rss.xml")
+                },
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/feed.xml")
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_jekyll() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head></head><body><!-- Begin Jekyll SEO tag v2.3.0 -->First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/feed.xml"),
+                guess("http://example.com/atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_github_io() {
+        let base = Url::parse("http://example.github.io/").unwrap();
+        let html = r#"<html><head></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.github.io/feed.xml"),
+                guess("http://example.github.io/atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_ghost() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Ghost 5.21" /></head><body>First post!</body</html>"#;
+        let url = Url::parse("http://example.com/rss/").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Guess,
+                Confidence::Low,
+                FeedSource::Guess
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_guess_ghost_v2() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Ghost 2.38" /></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("http://example.com/rss")])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress_v1() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 1.2.2" /></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("http://example.com/wp-rss2.php")])
+        );
+    }
+
+    #[test]
+    fn test_cms_version_parse() {
+        assert_eq!(
+            CmsVersion::parse("Ghost 5.21", "ghost"),
+            Some(CmsVersion {
+                major: 5,
+                minor: 21
+            })
+        );
+        assert_eq!(
+            CmsVersion::parse("WordPress 6.4.2", "wordpress"),
+            Some(CmsVersion { major: 6, minor: 4 })
+        );
+        assert_eq!(
+            CmsVersion::parse("Hugo v0.120.4", "hugo"),
+            Some(CmsVersion {
+                major: 0,
+                minor: 120
+            })
+        );
+        assert_eq!(CmsVersion::parse("WordPress.com", "wordpress"), None);
+        assert_eq!(CmsVersion::parse("Ghost 5.21", "wordpress"), None);
+    }
+
+    #[test]
+    fn test_guess_site_root() {
+        let base = Url::parse("https://example.com/myblog/2021/05/first-post/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 5.8" /></head><body>First post!</body</html>"#;
+        let config = FeedFinderConfig::new().with_site_root("/myblog");
+        assert_eq!(
+            detect_feeds_with_config(&base, html, &config),
+            Ok(vec![guess("https://example.com/myblog/feed")])
+        );
+    }
+
+    #[test]
+    fn test_guess_hugo_non_root() {
+        let base = Url::parse("http://example.com/blog/post/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Hugo 0.27.1" /></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/index.xml"),
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/rss.xml")
+                },
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/feed.xml")
+                },
+                guess("http://example.com/blog/index.xml"),
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/blog/rss.xml")
+                },
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/blog/feed.xml")
+                },
+                guess("http://example.com/blog/post/index.xml"),
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/blog/post/rss.xml")
+                },
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/blog/post/feed.xml")
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_hugo_old_version() {
+        let base = Url::parse("http://example.com/blog/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Hugo 0.19" /></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/index.xml"),
+                guess("http://example.com/blog/index.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_hugo_other_old_generator() {
+        // Only the versions of the platform that matched change its feed path
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Hugo 0.27.1" /><meta name="generator" content="Ghost 2.0" /></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/index.xml"),
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/rss.xml")
+                },
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/feed.xml")
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_hugo_taxonomy() {
+        let base = Url::parse("http://example.com/tags/rust/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Hugo 0.120.4" /></head><body>Posts tagged rust</body</html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| feed.url().as_str())
+                .collect::<Vec<_>>(),
+            vec![
+                "http://example.com/index.xml",
+                "http://example.com/rss.xml",
+                "http://example.com/feed.xml",
+                "http://example.com/tags/index.xml",
+                "http://example.com/tags/rss.xml",
+                "http://example.com/tags/feed.xml",
+                "http://example.com/tags/rust/index.xml",
+                "http://example.com/tags/rust/rss.xml",
+                "http://example.com/tags/rust/feed.xml",
+            ]
+        );
+        assert_eq!(feeds[6], guess("http://example.com/tags/rust/index.xml"));
+    }
+
+    #[test]
+    fn test_guess_jekyll_non_root() {
+        let base = Url::parse("http://example.github.io/blog/post/").unwrap();
+        let html = r#"<html><head></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.github.io/feed.xml"),
+                guess("http://example.github.io/atom.xml"),
+                guess("http://example.github.io/blog/feed.xml"),
+                guess("http://example.github.io/blog/atom.xml"),
+                guess("http://example.github.io/blog/post/feed.xml"),
+                guess("http://example.github.io/blog/post/atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_youtube_channel() {
+        let base = Url::parse("https://www.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA").unwrap();
+        let html = r#"<html><head></head><body>YouTube</body</html>"#;
+        let url = Url::parse(
+            "https://www.youtube.com/feeds/videos.xml?channel_id=UCaYhcUwRBNscFNUKTjgPFiA",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                hub: Some(Url::parse("https://pubsubhubbub.appspot.com/").unwrap()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_youtube_channel_community() {
+        let base = Url::parse("https://www.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA/community")
+            .unwrap();
+        let html = r#"<html><head></head><body>Community posts</body</html>"#;
+        let url = Url::parse(
+            "https://www.youtube.com/feeds/videos.xml?channel_id=UCaYhcUwRBNscFNUKTjgPFiA",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                hub: Some(Url::parse("https://pubsubhubbub.appspot.com/").unwrap()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_youtube_channel_variants() {
+        let html = r#"<html><head></head><body>Videos</body</html>"#;
+        for base in &[
+            "http://www.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA",
+            "https://youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA/",
+            "https://m.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA/videos?view=0",
+        ] {
+            let base = Url::parse(base).unwrap();
+            let feeds = detect_feeds(&base, html).unwrap();
+            assert_eq!(
+                feeds[0].url().as_str(),
+                "https://www.youtube.com/feeds/videos.xml?channel_id=UCaYhcUwRBNscFNUKTjgPFiA"
+            );
+        }
+    }
+
+    #[test]
+    fn test_youtube_channel_without_id() {
+        let base = Url::parse("https://www.youtube.com/channel/").unwrap();
+        let html = r#"<html><head></head><body>Videos</body</html>"#;
+        assert_eq!(detect_feeds(&base, html), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_youtube_user() {
+        let base = Url::parse("https://www.youtube.com/user/wezmnet").unwrap();
+        let html = r#"<html><head></head><body>YouTube</body</html>"#;
+        let url = Url::parse("https://www.youtube.com/feeds/videos.xml?user=wezmnet").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                hub: Some(Url::parse("https://pubsubhubbub.appspot.com/").unwrap()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_youtube_playlist() {
+        let base =
+            Url::parse("https://www.youtube.com/playlist?list=PLTOeCUgrkpMNEHx6j0vCH0cuyAIVZadnc")
+                .unwrap();
+        let html = r#"<html><head></head><body>YouTube</body</html>"#;
+        let url = Url::parse(
+            "https://www.youtube.com/feeds/videos.xml?playlist_id=PLTOeCUgrkpMNEHx6j0vCH0cuyAIVZadnc",
+        ).unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                hub: Some(Url::parse("https://pubsubhubbub.appspot.com/").unwrap()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_youtube_watch_playlist() {
+        let base =
+            Url::parse("https://www.youtube.com/watch?v=0gjFYpvHyrY&list=FLOEg2K4TcePNx9SdGdR0zpg")
+                .unwrap();
+        let html = r#"<html><head></head><body>YouTube</body</html>"#;
+        let url = Url::parse(
+            "https://www.youtube.com/feeds/videos.xml?playlist_id=FLOEg2K4TcePNx9SdGdR0zpg",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                hub: Some(Url::parse("https://pubsubhubbub.appspot.com/").unwrap()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_self_feed_atom_self_link() {
+        let base = Url::parse("http://example.com/feed").unwrap();
+        let html = r#"<?xml version="1.0" encoding="utf-8"?>
+        <feed xmlns="http://www.w3.org/2005/Atom">
+            <title>Example</title>
+            <link href="http://example.com/"/>
+            <link rel="self" href="http://example.com/atom.xml"/>
+        </feed>"#;
+        let url = Url::parse("http://example.com/atom.xml").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some(String::from("Example")),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::Content)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_self_feed_rss_atom_self_link() {
+        let base = Url::parse("http://example.com/feed").unwrap();
+        let html = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom">
+            <channel>
+                <title>Example</title>
+                <link>http://example.com/</link>
+                <atom:link href="http://example.com/feed.xml" rel="self" type="application/rss+xml" />
+                <item><title>First post</title><link>http://example.com/first</link></item>
+            </channel>
+        </rss>"#;
+        let url = Url::parse("http://example.com/feed.xml").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some(String::from("Example")),
+                ..Feed::candidate(url, FeedType::Rss, Confidence::High, FeedSource::Content)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_self_feed_xml_stylesheet() {
+        let base = Url::parse("http://example.com/feed.xml").unwrap();
+        let html = r#"<?xml version="1.0" encoding="utf-8"?>
+<?xml-stylesheet type="text/xsl" href="/assets/pretty-feed.xsl"?>
+<rss version="2.0">
+  <channel>
+    <title>Example Blog</title>
+    <link>http://example.com/</link>
+    <item><title>First post</title><link>http://example.com/first-post/</link></item>
+  </channel>
+</rss>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some("Example Blog".to_string()),
+                ..Feed::candidate(
+                    base.clone(),
+                    FeedType::Rss,
+                    Confidence::High,
+                    FeedSource::Content
+                )
+            },])
+        );
+    }
+
+    #[test]
+    fn test_self_feed_rss_channel_link() {
+        // The channel link is the website, so the feed is the URL the content came from
+        let base = Url::parse("http://example.com/feed").unwrap();
+        let html = r#"<?xml version="1.0" encoding="UTF-8"?>
+        <rss version="2.0">
+            <channel>
+                <title>Example</title>
+                <link>http://example.com/</link>
+                <item><title>First post</title><link>http://example.com/first</link></item>
+            </channel>
+        </rss>"#;
+        let url = Url::parse("http://example.com/feed").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some(String::from("Example")),
+                ..Feed::candidate(url, FeedType::Rss, Confidence::High, FeedSource::Content)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_guess_hexo() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Hexo 6.3.0"></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/atom.xml"),
+                guess("http://example.com/rss2.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_hexo_archive() {
+        let base = Url::parse("http://example.com/archives/2021/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Hexo 6.3.0"></head><body>Archives</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/atom.xml"),
+                guess("http://example.com/rss2.xml"),
+                guess("http://example.com/archives/atom.xml"),
+                guess("http://example.com/archives/rss2.xml"),
+                guess("http://example.com/archives/2021/atom.xml"),
+                guess("http://example.com/archives/2021/rss2.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_pelican() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Pelican" /></head><body>First post!</body</html>"#;
+        let url = Url::parse("http://example.com/feeds/all.atom.xml").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Guess,
+                Confidence::Low,
+                FeedSource::Guess
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_guess_pelican_category() {
+        let base = Url::parse("http://example.com/category/rust.html").unwrap();
+        let html = r#"<html><head><link rel="stylesheet" href="/theme/css/main.css" /></head><body>Proudly powered by Pelican</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/feeds/rust.atom.xml"),
+                guess("http://example.com/feeds/all.atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_pelican_category_directory() {
+        let base = Url::parse("http://example.com/category/rust/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Pelican" /></head><body>Posts</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/feeds/rust.atom.xml"),
+                guess("http://example.com/feeds/all.atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_zola() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Zola"></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/atom.xml"),
+                guess("http://example.com/rss.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_zola_section() {
+        let base = Url::parse("http://example.com/blog/").unwrap();
+        let html = r#"<html><head><script src="/elasticlunr.min.js"></script></head><body>Posts</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/atom.xml"),
+                guess("http://example.com/rss.xml"),
+                guess("http://example.com/blog/atom.xml"),
+                guess("http://example.com/blog/rss.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_hugo_not_zola() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Hugo 0.111.3"><script src="/js/elasticlunr.min.js"></script></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/index.xml"),
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/rss.xml")
+                },
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/feed.xml")
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_eleventy() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Eleventy v2.0.1"></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/feed/feed.xml"),
+                guess("http://example.com/feed.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_eleventy_non_root() {
+        let base = Url::parse("http://example.com/blog/first-post/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Eleventy v2.0.1"></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/feed/feed.xml"),
+                guess("http://example.com/feed.xml"),
+                guess("http://example.com/blog/feed/feed.xml"),
+                guess("http://example.com/blog/feed.xml"),
+                guess("http://example.com/blog/first-post/feed/feed.xml"),
+                guess("http://example.com/blog/first-post/feed.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_eleventy_mentioned() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head></head><body>I rebuilt this site with Eleventy.</body</html>"#;
+        assert_eq!(detect_feeds(&base, html), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_guess_gatsby() {
+        let base = Url::parse("http://example.com/blog/2023/05/first-post/").unwrap();
+        let html = r#"<html><head><link as="fetch" rel="preload" href="/page-data/blog/2023/05/first-post/page-data.json" crossorigin="anonymous"></head><body><div id="___gatsby"><div>First post!</div></div></body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/rss.xml"),
+                Feed {
+                    confidence: Confidence::VeryLow,
+                    ..guess("http://example.com/feed.xml")
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_youtube_shorts() {
+        let base = Url::parse("https://www.youtube.com/shorts/4lZxGzHfMFg").unwrap();
+        let html = r#"<html><head></head><body><div itemscope itemtype="http://schema.org/VideoObject"><meta itemprop="channelId" content="UCaYhcUwRBNscFNUKTjgPFiA"></div></body</html>"#;
+        let url = Url::parse(
+            "https://www.youtube.com/feeds/videos.xml?channel_id=UCaYhcUwRBNscFNUKTjgPFiA",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                hub: Some(Url::parse("https://pubsubhubbub.appspot.com/").unwrap()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_youtube_shorts_channel_url() {
+        let base = Url::parse("https://www.youtube.com/shorts/4lZxGzHfMFg").unwrap();
+        let html = r#"<html><head></head><body><span itemprop="author" itemscope itemtype="http://schema.org/Person"><link itemprop="url" href="http://www.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA"></span></body</html>"#;
+        let url = Url::parse(
+            "https://www.youtube.com/feeds/videos.xml?channel_id=UCaYhcUwRBNscFNUKTjgPFiA",
+        )
+        .unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                hub: Some(Url::parse("https://pubsubhubbub.appspot.com/").unwrap()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::YouTube)
+            },])
+        );
+    }
+
+    #[test]
+    fn test_guess_github_io_project() {
+        let base = Url::parse("https://example.github.io/project/").unwrap();
+        let html = r#"<html><head></head><body>Project page</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.github.io/feed.xml"),
+                guess("https://example.github.io/atom.xml"),
+                guess("https://example.github.io/project/feed.xml"),
+                guess("https://example.github.io/project/atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_gitlab_io() {
+        let base = Url::parse("https://example.gitlab.io/").unwrap();
+        let html = r#"<html><head></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.gitlab.io/feed.xml"),
+                guess("https://example.gitlab.io/atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_gitlab_io_project() {
+        let base = Url::parse("https://example.gitlab.io/project/").unwrap();
+        let html = r#"<html><head></head><body>Project page</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.gitlab.io/feed.xml"),
+                guess("https://example.gitlab.io/atom.xml"),
+                guess("https://example.gitlab.io/project/feed.xml"),
+                guess("https://example.gitlab.io/project/atom.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_nikola() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Nikola (getnikola.com)"></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/rss.xml"),
+                guess("http://example.com/index.atom"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_nikola_language() {
+        let base = Url::parse("http://example.com/en/posts/first-post/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Nikola (getnikola.com)"></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("http://example.com/en/rss.xml"),
+                guess("http://example.com/en/index.atom"),
+                guess("http://example.com/rss.xml"),
+                guess("http://example.com/index.atom"),
+                guess("http://example.com/en/posts/rss.xml"),
+                guess("http://example.com/en/posts/index.atom"),
+                guess("http://example.com/en/posts/first-post/rss.xml"),
+                guess("http://example.com/en/posts/first-post/index.atom"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_mkdocs() {
+        let base = Url::parse("https://docs.example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="mkdocs-1.5.3, mkdocs-material-9.4.6"></head><body>Docs</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://docs.example.com/feed_rss_created.xml"),
+                guess("https://docs.example.com/feed_rss_updated.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_mkdocs_project() {
+        let base = Url::parse("https://example.github.io/project/").unwrap();
+        let html = r#"<html><head><script src="/project/mkdocs/js/bootstrap.min.js"></script></head><body>Docs</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.github.io/feed_rss_created.xml"),
+                guess("https://example.github.io/feed_rss_updated.xml"),
+                guess("https://example.github.io/project/feed_rss_created.xml"),
+                guess("https://example.github.io/project/feed_rss_updated.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_discourse_topic() {
+        let base = Url::parse("https://forum.example.com/t/welcome-to-the-forum/1234/5").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Discourse 3.1.0 - https://github.com/discourse/discourse version 1234abcd"></head><body>Welcome!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess(
+                "https://forum.example.com/t/welcome-to-the-forum/1234.rss"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_guess_discourse_category() {
+        let base = Url::parse("https://forum.example.com/c/support/5").unwrap();
+        let html = r#"<html><head><link rel="preload" href="https://discourse-cdn.example.com/assets/start.js" as="script"></head><body>Support</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://forum.example.com/c/support/5.rss")])
+        );
+    }
+
+    #[test]
+    fn test_guess_discourse_root() {
+        let base = Url::parse("https://forum.example.com/").unwrap();
+        let html = r#"<html><head><meta id="data-discourse-setup" data-base-url="https://forum.example.com"></head><body>Latest topics</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://forum.example.com/latest.rss")])
+        );
+    }
+
+    #[test]
+    fn test_guess_phpbb_index() {
+        let base = Url::parse("https://example.com/forum/").unwrap();
+        let html = r#"<html><head></head><body><a href="./viewforum.php?f=5&amp;sid=abc">General</a><div class="copyright">Powered by <a href="https://www.phpbb.com/">phpBB</a>&reg; Forum Software</div></body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/forum/app.php/feed"),
+                guess("https://example.com/forum/feed.php"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_phpbb_viewforum() {
+        let base = Url::parse("https://example.com/forum/viewforum.php?f=5").unwrap();
+        let html = r#"<html><head></head><body><div class="copyright">Powered by <a href="https://www.phpbb.com/">phpBB</a>&reg; Forum Software</div></body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/forum/app.php/feed/forum/5"),
+                guess("https://example.com/forum/feed.php?f=5"),
+                guess("https://example.com/forum/app.php/feed"),
+                guess("https://example.com/forum/feed.php"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_phpbb_viewtopic() {
+        let base = Url::parse("https://example.com/viewtopic.php?t=10").unwrap();
+        let html =
+            r#"<html><head></head><body><a href="./viewforum.php?f=5">General</a></body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/app.php/feed"),
+                guess("https://example.com/feed.php"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_vbulletin_index() {
+        let base = Url::parse("https://example.com/forums/index.php").unwrap();
+        let html = r#"<html><head><meta name="generator" content="vBulletin 4.2.5" /></head><body>Forums</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess(
+                "https://example.com/forums/external.php?type=RSS2"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_guess_vbulletin_forumdisplay() {
+        let base = Url::parse("https://example.com/forums/forumdisplay.php?f=12").unwrap();
+        let html = r#"<html><head><meta name="generator" content="vBulletin 4.2.5" /></head><body>General Discussion</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/forums/external.php?type=RSS2&forumids=12"),
+                guess("https://example.com/forums/external.php?type=RSS2"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_mediawiki_main_page() {
+        let base = Url::parse("https://wiki.example.org/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="MediaWiki 1.41.0"><link rel="stylesheet" href="/w/load.php?lang=en&amp;modules=site.styles&amp;only=styles&amp;skin=vector"></head><body>Welcome</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess(
+                "https://wiki.example.org/w/index.php?title=Special%3ARecentChanges&feed=atom"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_guess_mediawiki_article() {
+        let base =
+            Url::parse("https://wiki.example.org/wiki/Caf%C3%A9_au_lait_&_croissants").unwrap();
+        let html = r#"<html><head><meta name="generator" content="MediaWiki 1.41.0"><script async="" src="/w/load.php?lang=en&amp;modules=startup&amp;only=scripts&amp;raw=1&amp;skin=vector"></script></head><body>Café au lait</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://wiki.example.org/w/index.php?title=Caf%C3%A9_au_lait_%26_croissants&action=history&feed=atom"),
+                guess("https://wiki.example.org/w/index.php?title=Special%3ARecentChanges&feed=atom"),
+            ])
+        );
+    }
+
+    #[cfg(feature = "toml-rules")]
+    #[test]
+    fn test_toml_rules_match_builtin() {
+        assert_eq!(rules::TOML_RULES, rules::BUILTIN_RULES);
+    }
+
+    #[test]
+    fn test_guess_dokuwiki() {
+        let base = Url::parse("https://example.com/doku.php?id=start").unwrap();
+        let html = r#"<html><head><meta name="generator" content="DokuWiki"/><link rel="stylesheet" href="/lib/exe/css.php?t=dokuwiki&amp;tseed=1a2b3c"/></head><body>Welcome</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://example.com/feed.php")])
+        );
+    }
+
+    #[test]
+    fn test_guess_dokuwiki_subdirectory() {
+        let base = Url::parse("https://example.com/wiki/doku.php?id=start").unwrap();
+        let html = r#"<html><head><script src="/wiki/lib/exe/jquery.php?tseed=1a2b3c" defer="defer"></script></head><body>Welcome</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://example.com/wiki/feed.php")])
+        );
+    }
+
+    #[test]
+    fn test_guess_dokuwiki_namespace() {
+        let base =
+            Url::parse("https://example.com/wiki/doku.php?id=projects:feedfinder:roadmap").unwrap();
+        let html = r#"<html><head><meta name="generator" content="DokuWiki"/><link rel="stylesheet" href="/wiki/lib/exe/css.php?t=dokuwiki"/></head><body>Roadmap</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/wiki/feed.php?ns=projects:feedfinder"),
+                guess("https://example.com/wiki/feed.php"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_mastodon_profile() {
+        let base = Url::parse("https://fosstodon.org/@user").unwrap();
+        let html = r#"<html><head><link href="https://fosstodon.org/users/user" rel="alternate" type="application/activity+json"></head><body><div id="mastodon" data-props="{&quot;locale&quot;:&quot;en&quot;}"></div></body</html>"#;
+        let url = Url::parse("https://fosstodon.org/@user.rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Rss,
+                Confidence::Low,
+                FeedSource::Guess
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_guess_mastodon_profile_tab() {
+        let base = Url::parse("https://fosstodon.org/@user/media?page=2").unwrap();
+        let html = r#"<html><head></head><body><div id="mastodon" data-props="{&quot;locale&quot;:&quot;en&quot;}"></div></body</html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(feeds[0].url().as_str(), "https://fosstodon.org/@user.rss");
+    }
+
+    #[test]
+    fn test_guess_mastodon_post() {
+        let base = Url::parse("https://fosstodon.org/@user/109876543210987654").unwrap();
+        let html = r#"<html><head><link href="https://fosstodon.org/users/user/statuses/109876543210987654" rel="alternate" type="application/activity+json"></head><body><a href="https://fosstodon.org/@user">@user</a></body</html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(feeds[0].url().as_str(), "https://fosstodon.org/@user.rss");
+    }
+
+    #[test]
+    fn test_guess_pleroma_users() {
+        let base = Url::parse("https://pleroma.example.com/users/lain/statuses").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Pleroma 2.5.2"></head><body><noscript>To use Pleroma, please enable JavaScript.</noscript><div id="app"></div></body</html>"#;
+        let url = Url::parse("https://pleroma.example.com/users/lain/feed.atom").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Atom,
+                Confidence::Low,
+                FeedSource::Guess
+            ),])
+        );
+    }
+
+    #[test]
+    fn test_guess_akkoma_profile() {
+        let base = Url::parse("https://akkoma.example.com/@lain").unwrap();
+        let html = r#"<html><head><link rel="alternate" type="application/activity+json" href="https://akkoma.example.com/users/lain"></head><body><noscript>To use Akkoma, please enable JavaScript.</noscript><div id="app"></div></body</html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| feed.url().as_str())
+                .collect::<Vec<_>>(),
+            vec!["https://akkoma.example.com/users/lain/feed.atom"]
+        );
+    }
+
+    #[test]
+    fn test_guess_pleroma_front_page() {
+        let base = Url::parse("https://pleroma.example.com/main/all").unwrap();
+        let html = r#"<html><head></head><body><noscript>To use Pleroma, please enable JavaScript.</noscript><div id="app"></div></body</html>"#;
+        assert_eq!(detect_feeds(&base, html), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_guess_pixelfed_profile() {
+        let html = r#"<html><head><meta name="generator" content="pixelfed"></head><body><div id="content"></div></body</html>"#;
+        for base in &[
+            "https://pixelfed.example.com/dansup",
+            "https://pixelfed.example.com/users/dansup",
+        ] {
+            let base = Url::parse(base).unwrap();
+            let url = Url::parse("https://pixelfed.example.com/users/dansup.atom").unwrap();
+            assert_eq!(
+                detect_feeds(&base, html),
+                Ok(vec![Feed::candidate(
+                    url,
+                    FeedType::Atom,
+                    Confidence::Low,
+                    FeedSource::Guess
+                ),])
+            );
+        }
+    }
+
+    #[test]
+    fn test_guess_pixelfed_photo() {
+        let base = Url::parse("https://pixelfed.example.com/p/dansup/123456789").unwrap();
+        let html = r#"<html><head><script type="text/javascript" src="/js/pixelfed.js?id=abc123"></script></head><body>Photo</body</html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| feed.url().as_str())
+                .collect::<Vec<_>>(),
+            vec!["https://pixelfed.example.com/users/dansup.atom"]
+        );
+    }
+
+    #[test]
+    fn test_guess_pixelfed_photo_id_only() {
+        let base = Url::parse("https://pixelfed.example.com/p/123456789").unwrap();
+        let html = r#"<html><head><meta name="generator" content="pixelfed"><link href="https://pixelfed.example.com/p/dansup/123456789" rel="alternate" type="application/activity+json"></head><body>Photo</body</html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds
+                .iter()
+                .map(|feed| feed.url().as_str())
+                .collect::<Vec<_>>(),
+            vec!["https://pixelfed.example.com/users/dansup.atom"]
+        );
+    }
+
+    #[test]
+    fn test_guess_write_as() {
+        let base = Url::parse("https://write.as/matt/my-first-post").unwrap();
+        let html = r#"<html><head></head><body>My first post</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://write.as/matt/feed/")])
+        );
+    }
+
+    #[test]
+    fn test_guess_writefreely_multiple_blogs() {
+        let base = Url::parse("https://blogs.example.com/notes/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WriteFreely"></head><body>Notes</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://blogs.example.com/notes/feed/")])
+        );
+    }
+
+    #[test]
+    fn test_guess_writefreely_single_user() {
+        let base = Url::parse("https://blog.example.com/my-first-post").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WriteFreely"></head><body>My first post</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://blog.example.com/feed/")])
+        );
+    }
+
+    #[test]
+    fn test_guess_bearblog() {
+        let base = Url::parse("https://herman.bearblog.dev/").unwrap();
+        let html = r#"<html><head><title>Herman's blog</title></head><body>Blog</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://herman.bearblog.dev/feed/")])
+        );
+    }
+
+    #[test]
+    fn test_guess_bearblog_custom_domain() {
+        let base = Url::parse("https://blog.example.com/").unwrap();
+        let html = r#"<html><head><title>Blog</title></head><body><footer><span>Powered by <a href="https://bearblog.dev">ʕ•ᴥ•ʔ Bear</a></span></footer></body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://blog.example.com/feed/")])
+        );
+    }
+
+    #[test]
+    fn test_guess_bearblog_post() {
+        let base = Url::parse("https://blog.example.com/my-first-post/").unwrap();
+        let html = r#"<html><head><title>My first post</title></head><body><footer><a href="https://bearblog.dev/">Made with Bear ʕ•ᴥ•ʔ</a></footer></body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://blog.example.com/feed/")])
+        );
+    }
+
+    #[test]
+    fn test_guess_feed_application_name() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 5.2" /><meta name="application-name" content="Example Podcast RSS" /></head><body>Episode 1</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                confidence: Confidence::Medium,
+                ..guess("http://example.com/feed")
+            }])
+        );
+    }
+
+    #[test]
+    fn test_guess_other_application_name() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 5.2" /><meta name="application-name" content="Example" /></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("http://example.com/feed")])
+        );
+    }
+
+    #[test]
+    fn test_well_known_feeds() {
+        let base = Url::parse("http://example.com/about/").unwrap();
+        let html = r#"<html><head><link rel="alternate" title="Feeds" href="/.well-known/feeds"></head><body>About us</body></html>"#;
+        let url = Url::parse("http://example.com/.well-known/feeds").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some("Feeds".to_string()),
+                ..Feed::candidate(url, FeedType::Link, Confidence::Low, FeedSource::WellKnown)
+            }])
+        );
+    }
+
+    #[test]
+    fn test_well_known_host_meta() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="lrdd" href="http://example.com/.well-known/host-meta"></head><body><a href="/.well-known/host-meta">Host meta</a></body></html>"#;
+        let url = Url::parse("http://example.com/.well-known/host-meta").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Link,
+                Confidence::Low,
+                FeedSource::WellKnown
+            )])
+        );
+    }
+
+    #[test]
+    fn test_guess_tumblr_tag() {
+        let base = Url::parse("https://example.tumblr.com/tagged/cats").unwrap();
+        let html = r#"<html><head><link href="https://assets.tumblr.com/client/prod/standalone/blog-network-npf/index.build.css" rel="stylesheet"></head><body>Cats</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.tumblr.com/tagged/cats/rss"),
+                guess("https://example.tumblr.com/rss"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_tumblr_post() {
+        let base = Url::parse("https://blog.example.com/post/123456/my-first-post").unwrap();
+        let html = r#"<html><head><link href="https://assets.tumblr.com/client/prod/standalone/blog-network-npf/index.build.css" rel="stylesheet"></head><body>First post!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://blog.example.com/rss")])
+        );
+    }
+
+    #[test]
+    fn test_guess_tumblr_host() {
+        let base = Url::parse("https://example.tumblr.com/").unwrap();
+        let html =
+            r#"<html><head><title>Example</title></head><body><div id="root"></div></body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://example.tumblr.com/rss")])
+        );
+    }
+
+    #[test]
+    fn test_site_type_blog() {
+        let base = Url::parse("https://example.com/2024/05/hello/").unwrap();
+        let html = r#"<html><head><meta property="og:type" content="article"><link rel="manifest" href="/manifest.json"><link rel="apple-touch-icon" href="/icon.png"></head><body><article>Hello</article></body></html>"#;
+        assert_eq!(detect_site_type(&base, html), SiteType::Blog);
+    }
+
+    #[test]
+    fn test_site_type_news() {
+        let base = Url::parse("https://news.example.com/world/story").unwrap();
+        let html = r#"<html><head><script type="application/ld+json">{"@context":"https://schema.org","@type":"NewsArticle","headline":"Story"}</script><meta property="og:type" content="article"></head><body>Story</body></html>"#;
+        assert_eq!(detect_site_type(&base, html), SiteType::News);
+    }
+
+    #[test]
+    fn test_site_type_app() {
+        let base = Url::parse("https://app.example.com/").unwrap();
+        let html = r#"<html><head><link rel="manifest" href="/manifest.webmanifest"><meta name="apple-mobile-web-app-capable" content="yes"><link rel="apple-touch-icon" sizes="180x180" href="/apple-touch-icon-rss.png"></head><body><div id="root"></div></body></html>"#;
+        assert_eq!(detect_site_type(&base, html), SiteType::App);
+        assert_eq!(detect_feeds(&base, html), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_site_type_unknown() {
+        let base = Url::parse("https://example.com/").unwrap();
+        let html = r#"<html><head><link rel="apple-touch-icon" href="/icon.png"></head><body>Hello</body></html>"#;
+        assert_eq!(detect_site_type(&base, html), SiteType::Unknown);
+    }
+
+    #[test]
+    fn test_error_boxed() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body><a href="http://[::1/feed.rss">RSS</a></body></html>"#;
+        let find =
+            || -> Result<Vec<Feed>, Box<dyn std::error::Error>> { Ok(detect_feeds(&base, html)?) };
+        let err = find().unwrap_err();
+        assert_eq!(err.to_string(), "invalid IPv6 address");
+        assert!(err.source().is_some());
+
+        let err: Box<dyn std::error::Error + Send + Sync> =
+            Box::new(FeedFinderError::Redirect(base.clone()));
+        assert_eq!(err.to_string(), "page redirects to http://example.com/");
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_guess_wordpress_category() {
+        let base = Url::parse("https://example.com/category/news/local/page/2/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.5.3" /></head><body>Local news</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/category/news/local/feed/"),
+                guess("https://example.com/feed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress_tag() {
+        let base = Url::parse("https://example.com/blog/tag/rust").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.5.3" /></head><body>Rust posts</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/blog/tag/rust/feed/"),
+                guess("https://example.com/feed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress_author() {
+        let base = Url::parse("https://example.com/author/wes/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.5.3" /></head><body>Posts by Wes</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/author/wes/feed/"),
+                guess("https://example.com/feed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress_date() {
+        let base = Url::parse("https://example.com/2024/05/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.5.3" /></head><body>May 2024</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/2024/05/feed/"),
+                guess("https://example.com/feed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress_post() {
+        let base = Url::parse("https://example.com/2024/05/hello-world/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.5.3" /></head><body>Hello world!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://example.com/feed")])
+        );
+    }
+
+    #[test]
+    fn test_body_form_links() {
+        let base = Url::parse("http://example.com/blog/").unwrap();
+        let html = r#"<html><body>
+        <form action="/search.php"><input type="text" name="q"></form>
+        <form action="generate-rss.php" title="Subscribe"><input type="hidden" name="cat" value="3"><input type="text" name="limit"><input type="submit" value="RSS"></form>
+        <form action="/rss.php" method="post"><input type="submit" value="RSS"></form>
+        </body></html>"#;
+        let url = Url::parse("http://example.com/blog/generate-rss.php?cat=3").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some("Subscribe".to_string()),
+                ..Feed::candidate(url, FeedType::Link, Confidence::Low, FeedSource::Form)
+            }])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress_com_host() {
+        let base =
+            Url::parse("https://example.blog.wordpress.com/2024/05/hello-world/amp/").unwrap();
+        let html =
+            r#"<html amp><head><title>Hello world</title></head><body>Hello world!</body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://example.blog.wordpress.com/feed")])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress_com_link() {
+        let base = Url::parse("https://example.com/about/").unwrap();
+        let html = r#"<html><head><title>About</title></head><body>I also write at <a href="https://example.wordpress.com/">my other blog</a></body</html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://example.com/feed")])
+        );
+
+        let base = Url::parse("https://wordpress.com.example.net/").unwrap();
+        let html = r#"<html><head><title>Not a blog</title></head><body>Hello</body</html>"#;
+        assert_eq!(detect_feeds(&base, html), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_detect_multiple_rel_tokens() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><head><link rel="alternate feed" type="application/atom+xml" title="Posts" href="/atom.xml"><link rel="Alternate Stylesheet" type="text/css" href="/dark.css"></head><body>Home</body></html>"#;
+        let url = Url::parse("http://example.com/atom.xml").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed {
+                title: Some("Posts".to_string()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::MetaLink)
+            }])
+        );
+    }
+
+    #[test]
+    fn test_guess_wordpress_rest_api() {
+        let base = Url::parse("https://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.5.3" /><link rel="https://api.w.org/" href="https://example.com/wp-json/" /></head><body>Home</body</html>"#;
+        let url = Url::parse("https://example.com/wp-json/wp/v2/posts?_embed=").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.com/feed"),
+                guess("https://example.com/feed/atom"),
+                Feed::candidate(url, FeedType::Json, Confidence::Low, FeedSource::Guess),
+            ])
+        );
     }
 
-    /// Get the title of the feed if available.
-    pub fn title(&self) -> Option<&str> {
-        self.title.as_deref()
+    #[test]
+    fn test_guess_wordpress_rest_route() {
+        let base = Url::parse("https://example.com/?p=1").unwrap();
+        let html = r#"<html><head><meta name="generator" content="WordPress 6.5.3" /><link rel="https://api.w.org/" href="https://example.com/index.php?rest_route=/" /></head><body>Hello world!</body</html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds
+                .last()
+                .map(|feed| (feed.url().as_str(), feed.feed_type())),
+            Some((
+                "https://example.com/index.php?rest_route=%2Fwp%2Fv2%2Fposts&_embed=",
+                &FeedType::Json
+            ))
+        );
     }
-}
 
-impl fmt::Display for FeedFinderError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            FeedFinderError::Url(err) => err.fmt(f),
-            FeedFinderError::Select => f.write_str("unable to select elements in doc"),
+    #[test]
+    fn test_guess_nitter_profile() {
+        let base = Url::parse("https://nitter.example.net/jack/status/20").unwrap();
+        let html = r#"<html><head><meta property="og:site_name" content="Nitter"><title>jack (@jack) | nitter</title></head><body><div class="profile-card">jack</div><div class="footer"><a href="https://github.com/zedeus/nitter">Nitter</a></div></body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://nitter.example.net/jack/rss")])
+        );
+
+        let base = Url::parse("https://nitter.example.net/jack/with_replies").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess(
+                "https://nitter.example.net/jack/with_replies/rss"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_guess_nitter_search() {
+        let base = Url::parse("https://nitter.example.net/search?f=tweets&q=rust").unwrap();
+        let html = r#"<html><head><meta property="og:site_name" content="Nitter"></head><body><form action="/search"><input name="q" value="rust"></form></body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess(
+                "https://nitter.example.net/search/rss?f=tweets&q=rust"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_guess_blogspot_host() {
+        let base = Url::parse("https://example.blogspot.com/2024/05/hello.html").unwrap();
+        let html = r#"<html><head><title>Hello</title></head><body></body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess(
+                "https://example.blogspot.com/feeds/posts/default"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_guess_blogger_custom_domain() {
+        let base = Url::parse("https://blog.example.com/").unwrap();
+        let html = r#"<html><head><meta content='blogger' name='generator'/><link href='https://www.blogger.com/static/v1/widgets/widget_css_bundle.css' rel='stylesheet' type='text/css'/></head><body>Home</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://blog.example.com/feeds/posts/default")])
+        );
+    }
+
+    #[test]
+    fn test_reddit_subreddit() {
+        let base = Url::parse("https://www.reddit.com/r/rust/").unwrap();
+        let html = r#"<html><head><title>r/rust</title></head><body>Posts</body></html>"#;
+        let url = Url::parse("https://www.reddit.com/r/rust/.rss").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![Feed::candidate(
+                url,
+                FeedType::Atom,
+                Confidence::High,
+                FeedSource::Reddit
+            )])
+        );
+    }
+
+    #[test]
+    fn test_reddit_sorted_listing() {
+        let base = Url::parse("https://old.reddit.com/r/rust/top?t=week#siteTable").unwrap();
+        let html = r#"<html><head><title>r/rust</title></head><body>Posts</body></html>"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds[0].url().as_str(),
+            "https://old.reddit.com/r/rust/top/.rss?t=week"
+        );
+    }
+
+    #[test]
+    fn test_guess_lemmy() {
+        let base = Url::parse("https://lemmy.example.org/c/rust").unwrap();
+        let html = r#"<html><head><title>Rust</title></head><body><div id="root"></div><script src="/static/js/client.js"></script><!-- lemmy-js-client --></body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://lemmy.example.org/feeds/c/rust.xml")])
+        );
+
+        let base = Url::parse("https://lemmy.example.org/").unwrap();
+        let html = r#"<html><head><title>Lemmy</title></head><body><footer><a href="https://github.com/LemmyNet/lemmy">Code</a></footer></body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://lemmy.example.org/feeds/local.xml"),
+                guess("https://lemmy.example.org/feeds/all.xml"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_shopify_article() {
+        let base = Url::parse("https://shop.example.com/blogs/journal/spring-collection").unwrap();
+        let html = r#"<html><head><link rel="stylesheet" href="//cdn.shopify.com/s/files/1/0001/t/1/assets/theme.css"></head><body>Spring is here</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://shop.example.com/blogs/journal.atom")])
+        );
+    }
+
+    #[test]
+    fn test_guess_shopify_blog() {
+        let base = Url::parse("https://shop.example.com/fr/blogs/journal/").unwrap();
+        let html = r#"<html><head><script>Shopify.theme = {"name":"Dawn","id":1};</script></head><body>Journal</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess(
+                "https://shop.example.com/fr/blogs/journal.atom"
+            )])
+        );
+    }
+
+    #[test]
+    fn test_guess_shopify_product() {
+        let base = Url::parse("https://shop.example.com/products/t-shirt").unwrap();
+        let html = r#"<html><head><script>Shopify.theme = {"name":"Dawn","id":1};</script></head><body>T-shirt</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://shop.example.com/blogs/news.atom")])
+        );
+    }
+
+    #[test]
+    fn test_youtube_hub() {
+        let base = Url::parse("https://www.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA").unwrap();
+        let feeds = detect_feeds(&base, "<html><body>Channel</body></html>").unwrap();
+        assert_eq!(
+            feeds[0].hub().map(Url::as_str),
+            Some("https://pubsubhubbub.appspot.com/")
+        );
+
+        let base = Url::parse("https://example.com/").unwrap();
+        let html = r#"<link rel="alternate" type="application/rss+xml" href="/feed.rss">"#;
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(feeds[0].hub(), None);
+    }
+
+    fn stack_exchange(url: &str) -> Vec<String> {
+        let base = Url::parse(url).unwrap();
+        detect_feeds(&base, "<html><body>Questions</body></html>")
+            .unwrap()
+            .iter()
+            .filter(|feed| feed.source() == FeedSource::StackExchange)
+            .map(|feed| feed.url().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_stack_exchange_question() {
+        assert_eq!(
+            stack_exchange("https://stackoverflow.com/questions/27589054/what-is-the-correct-way-to-use-lifetimes"),
+            vec!["https://stackoverflow.com/feeds/question/27589054"]
+        );
+        assert_eq!(
+            stack_exchange("https://unix.stackexchange.com/q/12345"),
+            vec!["https://unix.stackexchange.com/feeds/question/12345"]
+        );
+    }
+
+    #[test]
+    fn test_stack_exchange_tag() {
+        assert_eq!(
+            stack_exchange("https://stackoverflow.com/questions/tagged/rust"),
+            vec!["https://stackoverflow.com/feeds/tag/rust"]
+        );
+        assert_eq!(
+            stack_exchange("https://superuser.com/tags/bash/info"),
+            vec!["https://superuser.com/feeds/tag/bash"]
+        );
+    }
+
+    #[test]
+    fn test_stack_exchange_home() {
+        assert_eq!(
+            stack_exchange("https://ru.stackoverflow.com/"),
+            vec!["https://ru.stackoverflow.com/feeds"]
+        );
+        assert_eq!(
+            stack_exchange("https://askubuntu.com/users/42/example"),
+            vec!["https://askubuntu.com/feeds/user/42"]
+        );
+        assert!(stack_exchange("https://stackoverflow.com/jobs").is_empty());
+        assert!(stack_exchange("https://notstackoverflow.com/").is_empty());
+    }
+
+    #[test]
+    fn test_subscribe_widget() {
+        let base = Url::parse("http://example.com/").unwrap();
+        let html = r#"<html><body>
+        <p>Read the <a href="/news.xml">news</a></p>
+        <aside>
+          <h3>Subscribe</h3>
+          <ul>
+            <li><a href="/posts/feed">Posts</a></li>
+            <li><a href="/comments/feed">Comments</a></li>
+          </ul>
+        </aside>
+        </body></html>"#;
+        let link = |url: &str, confidence| {
+            Feed::candidate(
+                Url::parse(url).unwrap(),
+                FeedType::Link,
+                confidence,
+                FeedSource::BodyLink,
+            )
+        };
+
+        let config = FeedFinderConfig::new().with_subscribe_widgets(true);
+        assert_eq!(
+            detect_feeds_with_config(&base, html, &config),
+            Ok(vec![
+                link("http://example.com/posts/feed", Confidence::High),
+                link("http://example.com/comments/feed", Confidence::High),
+                link("http://example.com/news.xml", Confidence::Medium),
+            ])
+        );
+
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                link("http://example.com/news.xml", Confidence::Medium),
+                link("http://example.com/posts/feed", Confidence::Medium),
+                link("http://example.com/comments/feed", Confidence::Medium),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_weebly() {
+        let base = Url::parse("https://example.weebly.com/").unwrap();
+        let html = r#"<html><head><link rel="stylesheet" href="//cdn2.editmysite.com/css/sites.css"><script>_W.configDomain = "www.weebly.com";</script></head><body>Home</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.weebly.com/blog/feed"),
+                guess("https://example.weebly.com/1/feed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_weebly_post() {
+        let base = Url::parse("https://www.example.com/my-news/post-title").unwrap();
+        let html = r#"<html><head><script src="//cdn2.editmysite.com/js/site/main.js"></script><script>_W.configDomain = "www.weebly.com";</script></head><body>Post</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://www.example.com/my-news/feed"),
+                guess("https://www.example.com/blog/feed"),
+                guess("https://www.example.com/1/feed"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_github_repository() {
+        let feed = |url: &str| {
+            Feed::candidate(
+                Url::parse(url).unwrap(),
+                FeedType::Atom,
+                Confidence::High,
+                FeedSource::GitHub,
+            )
+        };
+        let html = "<html><body>Repository</body></html>";
+        let expected = vec![
+            feed("https://github.com/wezm/feedfinder/releases.atom"),
+            feed("https://github.com/wezm/feedfinder/commits.atom"),
+        ];
+
+        let base = Url::parse("https://github.com/wezm/feedfinder").unwrap();
+        assert_eq!(detect_feeds(&base, html), Ok(expected));
+
+        let base = Url::parse("ssh://git@github.com/wezm/feedfinder.git").unwrap();
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds[0].url().as_str(),
+            "https://github.com/wezm/feedfinder/releases.atom"
+        );
+    }
+
+    #[test]
+    fn test_github_not_repository() {
+        let html = "<html><body>GitHub</body></html>";
+        for url in &[
+            "https://github.com/",
+            "https://github.com/wezm",
+            "https://github.com/topics/rust",
+            "https://github.com/issues/assigned",
+            "https://github.com/pulls/review-requested",
+            "https://github.com/search/advanced",
+            "https://github.com/trending/rust",
+            "https://github.com/login/oauth",
+            "https://github.com/new/import",
+            "https://github.com/notifications/beta",
+            "https://github.com/marketplace/actions",
+            "https://github.com/users/wezm/projects",
+        ] {
+            let base = Url::parse(url).unwrap();
+            assert_eq!(detect_feeds(&base, html), Ok(Vec::new()), "{}", url);
         }
     }
-}
 
-impl std::error::Error for FeedFinderError {}
+    #[test]
+    fn test_guess_livejournal() {
+        let base = Url::parse("https://example.livejournal.com/12345.html").unwrap();
+        let html = r#"<html><head><title>Example</title></head><body>Entry</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.livejournal.com/data/rss"),
+                guess("https://example.livejournal.com/data/atom"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_livejournal_users_path() {
+        let base = Url::parse("https://www.livejournal.com/users/example/").unwrap();
+        let html = r#"<html><head><title>Example</title></head><body>Journal</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://www.livejournal.com/users/example/data/rss"),
+                guess("https://www.livejournal.com/users/example/data/atom"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_livejournal_front_page() {
+        let base = Url::parse("https://www.livejournal.com/").unwrap();
+        let html =
+            r#"<html><head><title>LiveJournal</title></head><body>Top entries</body></html>"#;
+        assert_eq!(detect_feeds(&base, html), Ok(Vec::new()));
+    }
+
+    const OPML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<opml version="2.0">
+  <head>
+    <title>Blogroll</title>
+  </head>
+  <body>
+    <outline text="Rust">
+      <outline type="rss" text="This Week in Rust" xmlUrl="https://this-week-in-rust.org/rss.xml" htmlUrl="https://this-week-in-rust.org/"/>
+      <outline type="atom" text="Inside Rust" title="Inside Rust Blog" xmlUrl="https://blog.rust-lang.org/inside-rust/feed.xml"/>
+    </outline>
+    <outline text="Home page" htmlUrl="https://example.com/"/>
+  </body>
+</opml>"#;
+
+    #[test]
+    fn test_opml() {
+        let base = Url::parse("https://example.com/blogroll.opml").unwrap();
+        assert_eq!(
+            detect_feeds(&base, OPML),
+            Ok(vec![Feed {
+                title: Some("Blogroll".to_string()),
+                ..Feed::candidate(
+                    base.clone(),
+                    FeedType::Opml,
+                    Confidence::High,
+                    FeedSource::Content
+                )
+            }])
+        );
+    }
+
+    #[test]
+    fn test_opml_outlines() {
+        let base = Url::parse("https://example.com/blogroll.opml").unwrap();
+        let config = FeedFinderConfig::new().with_opml_outlines(true);
+        let feeds = detect_feeds_with_config(&base, OPML, &config)
+            .unwrap()
+            .into_iter()
+            .map(|feed| (feed.url().to_string(), feed.type_, feed.title, feed.source))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            feeds,
+            vec![
+                (
+                    "https://example.com/blogroll.opml".to_string(),
+                    FeedType::Opml,
+                    Some("Blogroll".to_string()),
+                    FeedSource::Content
+                ),
+                (
+                    "https://this-week-in-rust.org/rss.xml".to_string(),
+                    FeedType::Rss,
+                    Some("This Week in Rust".to_string()),
+                    FeedSource::Opml
+                ),
+                (
+                    "https://blog.rust-lang.org/inside-rust/feed.xml".to_string(),
+                    FeedType::Atom,
+                    Some("Inside Rust Blog".to_string()),
+                    FeedSource::Opml
+                ),
+            ]
+        );
+    }
+
+    fn gitlab(url: &str, html: &str) -> Vec<String> {
+        let base = Url::parse(url).unwrap();
+        detect_feeds(&base, html)
+            .unwrap()
+            .iter()
+            .filter(|feed| feed.source() == FeedSource::GitLab)
+            .map(|feed| feed.url().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_gitlab_project() {
+        let html = "<html><body>Project</body></html>";
+        assert_eq!(
+            gitlab("https://gitlab.com/gitlab-org/gitlab-runner", html),
+            vec![
+                "https://gitlab.com/gitlab-org/gitlab-runner/-/releases.atom",
+                "https://gitlab.com/gitlab-org/gitlab-runner/-/tags?format=atom",
+                "https://gitlab.com/gitlab-org/gitlab-runner/-/commits/HEAD.atom",
+            ]
+        );
+        assert_eq!(
+            gitlab(
+                "https://gitlab.com/group/subgroup/project/-/tree/develop/src",
+                html
+            )[2],
+            "https://gitlab.com/group/subgroup/project/-/commits/develop.atom"
+        );
+    }
+
+    #[test]
+    fn test_gitlab_user() {
+        let html = "<html><body>Profile</body></html>";
+        assert_eq!(
+            gitlab("https://gitlab.com/example", html),
+            vec!["https://gitlab.com/example.atom"]
+        );
+        assert!(gitlab("https://gitlab.com/explore/projects", html).is_empty());
+    }
+
+    #[test]
+    fn test_gitlab_self_hosted() {
+        let html = r#"<html><head><meta content="GitLab" property="og:site_name"></head><body>Project</body></html>"#;
+        assert_eq!(
+            gitlab("https://git.example.com/team/app/-/issues/1", html),
+            vec![
+                "https://git.example.com/team/app/-/releases.atom",
+                "https://git.example.com/team/app/-/tags?format=atom",
+                "https://git.example.com/team/app/-/commits/HEAD.atom",
+            ]
+        );
+        assert!(gitlab("https://git.example.com/team/app", "<html></html>").is_empty());
+    }
+
+    #[test]
+    fn test_detect_site_metadata() {
+        let base = Url::parse("https://example.com/blog/").unwrap();
+        let html = r#"<html><head>
+        <title> Example Blog </title>
+        <link rel="apple-touch-icon" href="/apple-touch-icon.png">
+        <link rel="shortcut icon" href="favicon.ico">
+        <link rel="alternate" type="application/rss+xml" href="/blog/feed.rss">
+        </head><body>Posts</body></html>"#;
+        assert_eq!(
+            detect_site_metadata(&base, html),
+            Ok(SiteMetadata {
+                title: Some("Example Blog".to_string()),
+                icon: Some(Url::parse("https://example.com/blog/favicon.ico").unwrap()),
+                feeds: vec![feed(
+                    "https://example.com/blog/feed.rss",
+                    FeedType::Rss,
+                    Confidence::High,
+                    FeedSource::MetaLink
+                )],
+            })
+        );
+
+        let html = r#"<html><head><link rel="apple-touch-icon" href="/touch.png"></head></html>"#;
+        let metadata = detect_site_metadata(&base, html).unwrap();
+        assert_eq!(metadata.title(), None);
+        assert_eq!(
+            metadata.icon().map(Url::as_str),
+            Some("https://example.com/touch.png")
+        );
+    }
+
+    #[test]
+    fn test_guess_dreamwidth() {
+        let base = Url::parse("https://example.dreamwidth.org/?style=site").unwrap();
+        let html = r#"<html><head><title>Example</title></head><body>Journal</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.dreamwidth.org/data/rss"),
+                guess("https://example.dreamwidth.org/data/atom"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_dreamwidth_entry() {
+        let base =
+            Url::parse("https://example.dreamwidth.org/12345.html?style=site#comments").unwrap();
+        let html = r#"<html><head><title>Entry</title></head><body>Entry</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.dreamwidth.org/data/rss"),
+                guess("https://example.dreamwidth.org/data/atom"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_guess_dreamwidth_tag() {
+        let base = Url::parse("https://example.dreamwidth.org/tag/fandom").unwrap();
+        let html = r#"<html><head><title>Fandom</title></head><body>Entries</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![
+                guess("https://example.dreamwidth.org/data/rss?tag=fandom"),
+                guess("https://example.dreamwidth.org/data/rss"),
+                guess("https://example.dreamwidth.org/data/atom"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_third_party_npm() {
+        let html = "<html><body>Package</body></html>";
+        let base = Url::parse("https://www.npmjs.com/package/left-pad/v/1.3.0").unwrap();
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![feed(
+                "https://openrss.org/www.npmjs.com/package/left-pad",
+                FeedType::Rss,
+                Confidence::Medium,
+                FeedSource::ThirdParty
+            )])
+        );
+
+        let base =
+            Url::parse("https://www.npmjs.com/package/@types/node?activeTab=versions").unwrap();
+        let feeds = detect_feeds(&base, html).unwrap();
+        assert_eq!(
+            feeds[0].url().as_str(),
+            "https://openrss.org/www.npmjs.com/package/@types/node"
+        );
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        let base = Url::parse("https://www.npmjs.com/search?q=feed").unwrap();
+        assert_eq!(detect_feeds(&base, html), Ok(Vec::new()));
+    }
 
     #[test]
-    fn test_detect_meta_atom() {
+    fn test_detect_feeds_report_title() {
         let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><link rel="alternate" type="application/atom+xml" href="http://example.com/feed.atom"></head></html>"#;
-        let url = Url::parse("http://example.com/feed.atom").unwrap();
+        let html = r#"<html><head><title>Example Blog</title><link rel="alternate" type="application/rss+xml" href="/feed.rss"></head><body><h1>Posts</h1></body></html>"#;
+        let report = detect_feeds_report(&base, html);
+        assert_eq!(report.title(), Some("Example Blog"));
+        assert_eq!(report.feeds()[0].title(), None);
+
+        let report = detect_feeds_report(&base, "<html><body>Untitled</body></html>");
+        assert_eq!(report.title(), None);
+    }
+
+    #[test]
+    fn test_guess_typepad_entry() {
+        let base =
+            Url::parse("https://example.typepad.com/my_blog/2024/05/hello-world.html").unwrap();
+        let html = r#"<html><head><title>Hello world</title></head><body>Entry</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Atom,
-                title: None
-            },])
+            Ok(vec![
+                guess("https://example.typepad.com/my_blog/atom.xml"),
+                guess("https://example.typepad.com/my_blog/rss.xml"),
+            ])
         );
     }
 
     #[test]
-    fn test_detect_meta_rss() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><link rel="alternate" type="application/rss+xml" href="http://example.com/feed.rss"></head></html>"#;
-        let url = Url::parse("http://example.com/feed.rss").unwrap();
+    fn test_guess_typepad_custom_domain() {
+        let base = Url::parse("https://blog.example.com/2024/05/hello-world.html").unwrap();
+        let html = r#"<html><head><link rel="stylesheet" href="https://static.typepad.com/.shared:v20240501:typepad:en_us/themes/common/print.css" type="text/css"></head><body>Entry</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Rss,
-                title: None
-            },])
+            Ok(vec![
+                guess("https://blog.example.com/atom.xml"),
+                guess("https://blog.example.com/rss.xml"),
+            ])
         );
     }
 
     #[test]
-    fn test_detect_meta_rss_title() {
+    fn test_feed_new() {
         let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><link rel="alternate" type="application/rss+xml" href="http://example.com/feed.rss" title="RSS Feed"></head></html>"#;
-        let url = Url::parse("http://example.com/feed.rss").unwrap();
+        let html = r#"<link rel="alternate" type="application/rss+xml" href="/feed.rss">"#;
+        let mut feeds = detect_feeds(&base, html).unwrap();
+        let url = Url::parse("http://example.com/comments.atom").unwrap();
+        feeds.push(Feed::new(url.clone(), FeedType::Atom).with_title(String::from("Comments")));
+
         assert_eq!(
-            detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Rss,
-                title: Some(String::from("RSS Feed"))
-            },])
+            feeds[1],
+            Feed {
+                title: Some("Comments".to_string()),
+                ..Feed::candidate(url, FeedType::Atom, Confidence::High, FeedSource::Manual)
+            }
         );
     }
 
     #[test]
-    fn test_detect_meta_rss_title_multiple() {
-        let base = Url::parse("https://wordpress.com/blog/2021/12/07/drive-more-traffic-to-your-site-with-a-link-in-bio-social-links-page/").unwrap();
-        let html = r#"<html><head>
-        <link rel="alternate" type="application/rss+xml" title="WordPress.com Blog" href="https://wordpress.com/blog/feed/">
-        <link rel="alternate" type="application/rss+xml" title="WordPress.com News » Drive More Traffic To Your Site With a “Link In Bio” Social Links&nbsp;Page Comments Feed" href="https://wordpress.com/blog/2021/12/07/drive-more-traffic-to-your-site-with-a-link-in-bio-social-links-page/feed/">
-        </head></html>"#;
+    fn test_guess_grav_post() {
+        let base = Url::parse("https://example.com/blog/my-post").unwrap();
+        let html = r#"<html><head><meta name="generator" content="GravCMS" /></head><body>My post</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url: "https://wordpress.com/blog/feed/".parse().unwrap(),
-                type_: FeedType::Rss,
-                title: Some(String::from("WordPress.com Blog"))
-            },
-            Feed {
-                url: "https://wordpress.com/blog/2021/12/07/drive-more-traffic-to-your-site-with-a-link-in-bio-social-links-page/feed/".parse().unwrap(),
-                type_: FeedType::Rss,
-                title: Some(String::from("WordPress.com News » Drive More Traffic To Your Site With a “Link In Bio” Social Links\u{a0}Page Comments Feed"))
-            },])
+            Ok(vec![
+                guess("https://example.com/blog.rss"),
+                guess("https://example.com/blog.atom"),
+                guess("https://example.com/feed.rss"),
+            ])
         );
     }
 
     #[test]
-    fn test_detect_meta_rss_relative() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><link rel="alternate" type="application/rss+xml" href="/feed.rss"></head></html>"#;
-        let url = Url::parse("http://example.com/feed.rss").unwrap();
+    fn test_guess_grav_collection() {
+        let base = Url::parse("https://example.com/blog/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="GravCMS" /></head><body>Posts</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Rss,
-                title: None
-            },])
+            Ok(vec![
+                guess("https://example.com/blog.rss"),
+                guess("https://example.com/blog.atom"),
+                guess("https://example.com/feed.rss"),
+            ])
         );
     }
 
     #[test]
-    fn test_detect_meta_json_feed() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><link rel="alternate" type="application/json" href="http://example.com/feed.json"></head></html>"#;
-        let url = Url::parse("http://example.com/feed.json").unwrap();
+    fn test_guess_grav_root() {
+        let base = Url::parse("https://example.com/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="GravCMS" /></head><body>Home</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Json,
-                title: None
-            },])
+            Ok(vec![guess("https://example.com/feed.rss")])
         );
     }
 
     #[test]
-    fn test_body_link_feed() {
+    fn test_detect_meta_link_in_body() {
         let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><body><a href="/feed/">RSS</a></body</html>"#;
-        let url = Url::parse("http://example.com/feed/").unwrap();
+        let html = r#"<html><head><title>Example</title></head><body><div class="sidebar"><p>Follow along</p><link rel="alternate" type="application/rss+xml" title="Posts" href="/posts.rss"></div><a href="/feed.xml">Feed</a></body></html>"#;
+        let url = Url::parse("http://example.com/posts.rss").unwrap();
         assert_eq!(
             detect_feeds(&base, html),
             Ok(vec![Feed {
-                url,
-                type_: FeedType::Link,
-                title: None
-            },])
+                title: Some("Posts".to_string()),
+                ..Feed::candidate(url, FeedType::Rss, Confidence::High, FeedSource::MetaLink)
+            }])
         );
     }
 
     #[test]
-    fn test_body_link_xml() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><body><a href="/index.xml">RSS</a></body</html>"#;
-        let url = Url::parse("http://example.com/index.xml").unwrap();
+    fn test_self_feed_rss_1() {
+        let base = Url::parse("http://example.com/index.rdf").unwrap();
+        let xml = r#"<?xml version="1.0" encoding="utf-8"?>
+<rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#" xmlns="http://purl.org/rss/1.0/">
+  <channel rdf:about="http://example.com/">
+    <title>Example RSS 1.0</title>
+    <link>http://example.com/</link>
+  </channel>
+  <item rdf:about="http://example.com/1">
+    <title>First post</title>
+    <link>http://example.com/1</link>
+  </item>
+</rdf:RDF>"#;
         assert_eq!(
-            detect_feeds(&base, html),
+            detect_feeds(&base, xml),
             Ok(vec![Feed {
-                url,
-                type_: FeedType::Link,
-                title: None
-            },])
+                title: Some("Example RSS 1.0".to_string()),
+                ..Feed::candidate(
+                    base.clone(),
+                    FeedType::Rss,
+                    Confidence::High,
+                    FeedSource::Content
+                )
+            }])
         );
     }
 
+    fn gitea(url: &str, html: &str) -> Vec<String> {
+        let base = Url::parse(url).unwrap();
+        detect_feeds(&base, html)
+            .unwrap()
+            .iter()
+            .filter(|feed| feed.source() == FeedSource::Gitea)
+            .map(|feed| feed.url().to_string())
+            .collect()
+    }
+
     #[test]
-    fn test_body_link_rss() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><body><a href="/comments.rss">RSS</a></body</html>"#;
-        let url = Url::parse("http://example.com/comments.rss").unwrap();
+    fn test_gitea_repository() {
+        let html = r#"<html><head><meta name="keywords" content="go,git,self-hosted,gitea"><title>example/app - Gitea: Git with a cup of tea</title></head>
+        <body><div class="repository">README</div><footer><a href="https://about.gitea.com">Powered by Gitea</a></footer></body></html>"#;
         assert_eq!(
-            detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Link,
-                title: None
-            },])
+            gitea(
+                "https://git.example.com/example/app/src/branch/develop/src",
+                html
+            ),
+            vec![
+                "https://git.example.com/example/app.atom",
+                "https://git.example.com/example/app/commits/branch/develop.atom",
+            ]
+        );
+        assert_eq!(
+            gitea("https://git.example.com/example", html),
+            vec!["https://git.example.com/example.atom"]
         );
+        assert!(gitea("https://git.example.com/explore/repos", html).is_empty());
     }
 
     #[test]
-    fn test_body_link_atom() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html =
-            r#"<html><body><a href="http://other.example.com/posts.atom">RSS</a></body</html>"#;
-        let url = Url::parse("http://other.example.com/posts.atom").unwrap();
+    fn test_gitea_blog_post() {
+        let base = Url::parse("https://blog.example.com/2024/my-post").unwrap();
+        let html = r#"<html><head><meta name="keywords" content="gitea,forgejo,self-hosting"><meta name="author" content="Gitea fan"><title>Moving to Gitea</title></head>
+        <body><article>Our code is now powered by Gitea, see <a href="https://about.gitea.com">their site</a>.</article>
+        <footer><a href="/feed.xml">RSS</a></footer></body></html>"#;
         assert_eq!(
-            detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Link,
-                title: None
-            },])
+            detect_feeds(&base, html)
+                .unwrap()
+                .iter()
+                .map(|feed| (feed.url().as_str(), feed.source()))
+                .collect::<Vec<_>>(),
+            vec![("https://blog.example.com/feed.xml", FeedSource::BodyLink)]
         );
     }
 
     #[test]
-    fn test_guess_tumblr() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><link href="http://static.tumblr.com/example/jquery.fancybox-1.3.4.css" rel="stylesheet" type="text/css"></head><body>First post!</body</html>"#;
-        let url = Url::parse("http://example.com/rss").unwrap();
+    fn test_forgejo_repository() {
+        let html = "<html><body>Repository</body></html>";
         assert_eq!(
-            detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Guess,
-                title: None
-            },])
+            gitea("https://codeberg.org/forgejo/forgejo/issues/1", html),
+            vec!["https://codeberg.org/forgejo/forgejo.atom"]
         );
+        assert!(gitea("https://git.example.com/example/app", html).is_empty());
     }
 
     #[test]
-    fn test_guess_wordpress() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><meta name="generator" content="WordPress.com" /></head><body>First post!</body</html>"#;
-        let url = Url::parse("http://example.com/feed").unwrap();
+    fn test_platform_rule() {
+        let base = Url::parse("https://forum.example.com/t/welcome/1").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Discourse 3.2"></head><body><div id="flarum-loading">Loading...</div></body></html>"#;
+        let config = FeedFinderConfig::new().with_platform_rule(PlatformRule {
+            name: String::from("flarum"),
+            matches: PlatformMatch::MarkupContains(String::from("Flarum-Loading")),
+            feeds: vec![
+                PlatformFeed::RootPath(String::from("/atom")),
+                PlatformFeed::Transform(|url| url.join("feed.rss").ok()),
+            ],
+        });
         assert_eq!(
-            detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Guess,
-                title: None
-            },])
+            detect_feeds_with_config(&base, html, &config),
+            Ok(vec![
+                guess("https://forum.example.com/atom"),
+                guess("https://forum.example.com/t/welcome/feed.rss"),
+            ])
         );
     }
 
     #[test]
-    fn test_guess_hugo() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><meta name="generator" content="Hugo 0.27.1" /></head><body>First post!</body</html>"#;
-        let url = Url::parse("http://example.com/index.xml").unwrap();
+    fn test_platform_rule_no_match() {
+        let base = Url::parse("https://docs.example.com/project/guide/").unwrap();
+        let html = r#"<html><head><meta name="generator" content="Sphinx 7.2"></head><body>Guide</body></html>"#;
+        let config = FeedFinderConfig::new()
+            .with_platform_rule(PlatformRule {
+                name: String::from("example"),
+                matches: PlatformMatch::Domain(String::from(".example.org")),
+                feeds: vec![PlatformFeed::RootPath(String::from("/feed"))],
+            })
+            .with_platform_rule(PlatformRule {
+                name: String::from("sphinx"),
+                matches: PlatformMatch::Generator(String::from("Sphinx")),
+                feeds: vec![PlatformFeed::Segments(vec![String::from("rss.xml")])],
+            });
         assert_eq!(
-            detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Guess,
-                title: None
-            },])
+            detect_feeds_with_config(&base, html, &config),
+            Ok(vec![
+                guess("https://docs.example.com/rss.xml"),
+                guess("https://docs.example.com/project/rss.xml"),
+                guess("https://docs.example.com/project/guide/rss.xml"),
+            ])
         );
     }
 
     #[test]
-    fn test_guess_jekyll() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head></head><body><!-- Begin Jekyll SEO tag v2.3.0 -->First post!</body</html>"#;
-        let url = Url::parse("http://example.com/atom.xml").unwrap();
+    fn test_platform_rule_any_all() {
+        let base = Url::parse("https://forum.example.com/t/welcome").unwrap();
+        let config = FeedFinderConfig::new().with_platform_rule(PlatformRule {
+            name: String::from("forum"),
+            matches: PlatformMatch::Any(vec![
+                PlatformMatch::Generator(String::from("Forum")),
+                PlatformMatch::All(vec![
+                    PlatformMatch::MarkupContains(String::from("forum.js")),
+                    PlatformMatch::MarkupContains(String::from("forum.css")),
+                ]),
+            ]),
+            feeds: vec![PlatformFeed::RootPath(String::from("/posts.rss"))],
+        });
+        let feeds = |html| detect_feeds_with_config(&base, html, &config);
         assert_eq!(
-            detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Guess,
-                title: None
-            },])
+            feeds(r#"<html><head><meta name="generator" content="Forum 2"></head></html>"#),
+            Ok(vec![guess("https://forum.example.com/posts.rss")])
+        );
+        assert_eq!(
+            feeds(
+                r#"<html><head><link rel="stylesheet" href="/forum.css"><script src="/forum.js"></script></head></html>"#
+            ),
+            Ok(vec![guess("https://forum.example.com/posts.rss")])
+        );
+        assert_eq!(
+            feeds(r#"<html><head><script src="/forum.js"></script></head></html>"#),
+            Ok(vec![])
         );
     }
 
     #[test]
-    fn test_guess_github_io() {
-        let base = Url::parse("http://example.github.io/").unwrap();
-        let html = r#"<html><head></head><body>First post!</body</html>"#;
-        let url = Url::parse("http://example.github.io/atom.xml").unwrap();
+    fn test_platform_rule_host_lookalike() {
+        let html = r#"<html><head><title>Blog</title></head><body>Post</body></html>"#;
+        let config = FeedFinderConfig::new().with_platform_rule(PlatformRule {
+            name: String::from("example"),
+            matches: PlatformMatch::Domain(String::from("example.org")),
+            feeds: vec![PlatformFeed::RootPath(String::from("/feed"))],
+        });
+        let feeds = |url| detect_feeds_with_config(&Url::parse(url).unwrap(), html, &config);
         assert_eq!(
-            detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Guess,
-                title: None
-            },])
+            feeds("https://blog.example.org/post"),
+            Ok(vec![guess("https://blog.example.org/feed")])
+        );
+        assert_eq!(
+            feeds("https://example.org/post"),
+            Ok(vec![guess("https://example.org/feed")])
         );
+        assert_eq!(feeds("https://notexample.org/post"), Ok(vec![]));
     }
 
     #[test]
-    fn test_guess_ghost() {
-        let base = Url::parse("http://example.com/").unwrap();
-        let html = r#"<html><head><meta name="generator" content="Ghost 1.21" /></head><body>First post!</body</html>"#;
-        let url = Url::parse("http://example.com/rss/").unwrap();
+    fn test_platform_rule_url_template() {
+        let html =
+            r#"<html><head><title>Posts | Aggregator</title></head><body>Posts</body></html>"#;
+        let config = FeedFinderConfig::new().with_platform_rule(PlatformRule {
+            name: String::from("aggregator"),
+            matches: PlatformMatch::Domain(String::from("aggregator.example")),
+            feeds: vec![PlatformFeed::UrlTemplate(String::from(
+                "/api/v1/{seg0}/user/{seg2}/rss",
+            ))],
+        });
+        let base = Url::parse("https://aggregator.example/patreon/user/123").unwrap();
+        assert_eq!(
+            detect_feeds_with_config(&base, html, &config),
+            Ok(vec![guess(
+                "https://aggregator.example/api/v1/patreon/user/123/rss"
+            )])
+        );
+
+        let base = Url::parse("https://aggregator.example/patreon").unwrap();
+        assert_eq!(detect_feeds_with_config(&base, html, &config), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_guess_hashnode() {
+        let base = Url::parse("https://example.hashnode.dev/series/learning-rust").unwrap();
+        let html = r#"<html><head><title>Learning Rust</title></head><body>Posts</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Guess,
-                title: None
-            },])
+            Ok(vec![guess("https://example.hashnode.dev/rss.xml")])
         );
     }
 
     #[test]
-    fn test_guess_hugo_non_root() {
-        let base = Url::parse("http://example.com/blog/post/").unwrap();
-        let html = r#"<html><head><meta name="generator" content="Hugo 0.27.1" /></head><body>First post!</body</html>"#;
+    fn test_guess_hashnode_custom_domain() {
+        let base = Url::parse("https://blog.example.com/tag/rust").unwrap();
+        let html = r#"<html><head><link rel="preconnect" href="https://cdn.hashnode.com"></head><body>Posts tagged rust</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![
-                Feed {
-                    url: Url::parse("http://example.com/index.xml").unwrap(),
-                    type_: FeedType::Guess,
-                    title: None
-                },
-                Feed {
-                    url: Url::parse("http://example.com/blog/index.xml").unwrap(),
-                    type_: FeedType::Guess,
-                    title: None
-                },
-                Feed {
-                    url: Url::parse("http://example.com/blog/post/index.xml").unwrap(),
-                    type_: FeedType::Guess,
-                    title: None
-                },
-            ])
+            Ok(vec![guess("https://blog.example.com/rss.xml")])
         );
     }
 
     #[test]
-    fn test_guess_jekyll_non_root() {
-        let base = Url::parse("http://example.github.io/blog/post/").unwrap();
-        let html = r#"<html><head></head><body>First post!</body</html>"#;
+    fn test_guess_forem() {
+        let html = r#"<html><head><title>DEV Community</title></head><body>Posts</body></html>"#;
+        let feeds = |url| detect_feeds(&Url::parse(url).unwrap(), html).unwrap();
+        assert_eq!(
+            feeds("https://dev.to/example"),
+            vec![guess("https://dev.to/feed/example")]
+        );
+        assert_eq!(
+            feeds("https://dev.to/t/rust"),
+            vec![guess("https://dev.to/feed/tag/rust")]
+        );
+        assert_eq!(
+            feeds("https://dev.to/example/first-post-3b7k"),
+            vec![guess("https://dev.to/feed/example")]
+        );
+        assert_eq!(
+            feeds("https://dev.to/top/week"),
+            vec![guess("https://dev.to/feed")]
+        );
+    }
+
+    #[test]
+    fn test_guess_forem_instance() {
+        let base = Url::parse("https://community.example.com/example").unwrap();
+        let html = r#"<html><head><meta property="forem:domain" content="community.example.com"></head><body>Posts</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![
-                Feed {
-                    url: Url::parse("http://example.github.io/atom.xml").unwrap(),
-                    type_: FeedType::Guess,
-                    title: None
-                },
-                Feed {
-                    url: Url::parse("http://example.github.io/blog/atom.xml").unwrap(),
-                    type_: FeedType::Guess,
-                    title: None
-                },
-                Feed {
-                    url: Url::parse("http://example.github.io/blog/post/atom.xml").unwrap(),
-                    type_: FeedType::Guess,
-                    title: None
-                },
-            ])
+            Ok(vec![guess("https://community.example.com/feed/example")])
         );
     }
 
     #[test]
-    fn test_youtube_channel() {
-        let base = Url::parse("https://www.youtube.com/channel/UCaYhcUwRBNscFNUKTjgPFiA").unwrap();
-        let html = r#"<html><head></head><body>YouTube</body</html>"#;
-        let url = Url::parse(
-            "https://www.youtube.com/feeds/videos.xml?channel_id=UCaYhcUwRBNscFNUKTjgPFiA",
-        )
-        .unwrap();
+    fn test_guess_substack() {
+        let base = Url::parse("https://newsletter.example.com/p/issue-1").unwrap();
+        let html = r#"<html><head><link rel="preconnect" href="https://substackcdn.com"></head><body>Issue 1</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Atom,
-                title: None
-            },])
+            Ok(vec![guess("https://newsletter.example.com/feed")])
         );
     }
 
     #[test]
-    fn test_youtube_user() {
-        let base = Url::parse("https://www.youtube.com/user/wezmnet").unwrap();
-        let html = r#"<html><head></head><body>YouTube</body</html>"#;
-        let url = Url::parse("https://www.youtube.com/feeds/videos.xml?user=wezmnet").unwrap();
+    fn test_guess_buttondown() {
+        let base = Url::parse("https://buttondown.email/example/archive/issue-1/").unwrap();
+        let html = r#"<html><head><title>Issue 1</title></head><body>Issue 1</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Atom,
-                title: None
-            },])
+            Ok(vec![guess("https://buttondown.email/example/rss")])
         );
     }
 
     #[test]
-    fn test_youtube_playlist() {
-        let base =
-            Url::parse("https://www.youtube.com/playlist?list=PLTOeCUgrkpMNEHx6j0vCH0cuyAIVZadnc")
-                .unwrap();
-        let html = r#"<html><head></head><body>YouTube</body</html>"#;
-        let url = Url::parse(
-            "https://www.youtube.com/feeds/videos.xml?playlist_id=PLTOeCUgrkpMNEHx6j0vCH0cuyAIVZadnc",
-        ).unwrap();
+    fn test_guess_medium() {
+        let html = r#"<html><head><title>Medium</title></head><body>Story</body></html>"#;
+        let feeds = |url| detect_feeds(&Url::parse(url).unwrap(), html).unwrap();
+        assert_eq!(
+            feeds("https://medium.com/@example/first-story-1a2b3c4d5e6f?source=rss-1a2b3c4d5e6f------2"),
+            vec![guess("https://medium.com/feed/@example")]
+        );
+        assert_eq!(
+            feeds("https://medium.com/example-publication/first-story-1a2b3c4d5e6f?source=collection_home---4------0-----------------------"),
+            vec![guess("https://medium.com/feed/example-publication")]
+        );
+        assert_eq!(
+            feeds("https://medium.com/tag/rust"),
+            vec![guess("https://medium.com/feed/tag/rust")]
+        );
+        assert_eq!(
+            feeds("https://example.medium.com/first-story-1a2b3c4d5e6f"),
+            vec![guess("https://example.medium.com/feed")]
+        );
+        assert!(feeds("https://medium.com/").is_empty());
+    }
+
+    #[test]
+    fn test_guess_host_lookalikes() {
+        let html = r#"<html><head><title>Blog</title></head><body>Post</body></html>"#;
+        let feeds = |url| detect_feeds(&Url::parse(url).unwrap(), html).unwrap();
+        assert!(feeds("https://notmedium.com/@example/first-post").is_empty());
+        assert!(feeds("https://blog.notdev.to/example/first-post").is_empty());
+        assert!(feeds("https://rewrite.as/example/first-post").is_empty());
+        assert!(feeds("https://nottumblr.com/post/123/first-post").is_empty());
+        assert!(feeds("https://example.notblogspot.com/2024/01/first-post.html").is_empty());
+    }
+
+    #[test]
+    fn test_guess_medium_member_redirect() {
+        let base = Url::parse("https://medium.com/m/global-identity-provider/redirect?redirectUrl=https%3A%2F%2Fblog.example.com%2Ffirst-story-1a2b3c4d5e6f%3Fsource%3Demail-1a2b3c4d5e6f-1700000000000-digest.reader&source=email").unwrap();
+        let html = r#"<html><head><title>Medium</title></head><body>Redirecting</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Atom,
-                title: None
-            },])
+            Ok(vec![guess("https://blog.example.com/feed")])
+        );
+
+        let base = Url::parse("https://blog.example.com/first-story-1a2b3c4d5e6f?source=friends_link&sk=0123456789abcdef").unwrap();
+        let html = r#"<html><head><link rel="preconnect" href="https://cdn-client.medium.com"></head><body>Story</body></html>"#;
+        assert_eq!(
+            detect_feeds(&base, html),
+            Ok(vec![guess("https://blog.example.com/feed")])
         );
     }
 
     #[test]
-    fn test_youtube_watch_playlist() {
-        let base =
-            Url::parse("https://www.youtube.com/watch?v=0gjFYpvHyrY&list=FLOEg2K4TcePNx9SdGdR0zpg")
-                .unwrap();
-        let html = r#"<html><head></head><body>YouTube</body</html>"#;
-        let url = Url::parse(
-            "https://www.youtube.com/feeds/videos.xml?playlist_id=FLOEg2K4TcePNx9SdGdR0zpg",
-        )
-        .unwrap();
+    fn test_guess_mailchimp() {
+        let base = Url::parse("https://us1.campaign-archive.com/home/?u=abc123&id=def456").unwrap();
+        let html =
+            r#"<html><head><title>Past campaigns</title></head><body>Campaigns</body></html>"#;
         assert_eq!(
             detect_feeds(&base, html),
-            Ok(vec![Feed {
-                url,
-                type_: FeedType::Atom,
-                title: None
-            },])
+            Ok(vec![guess(
+                "https://us1.campaign-archive.com/feed?u=abc123&id=def456"
+            )])
         );
     }
 }
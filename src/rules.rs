@@ -0,0 +1,298 @@
+// The rules used to guess feeds from the software that generated a page.
+//
+// The rules are tried in order and the first one that matches determines the guesses. A rule
+// matches when any of its generators, markup, or hosts match, or all of its markup_all
+// matches. With the toml-rules feature the table is generated from rules.toml by the build
+// script instead, which must be kept in sync with BUILTIN_RULES.
+
+#[derive(Debug, PartialEq)]
+pub(crate) struct GuessRule {
+    pub(crate) name: &'static str,
+    // Prefixes of the lowercased generator meta tag content
+    pub(crate) generators: &'static [&'static str],
+    // Strings that appear in the lowercased markup
+    pub(crate) markup: &'static [&'static str],
+    // Strings that must all appear in the lowercased markup
+    pub(crate) markup_all: &'static [&'static str],
+    // Domains the host of the base URL is, or is a subdomain of
+    pub(crate) hosts: &'static [&'static str],
+    pub(crate) action: GuessAction,
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum GuessAction {
+    // Guess each of the files at each level of the path
+    Segments(&'static [&'static str]),
+    // Guess the path relative to the root of the site
+    Root(&'static str),
+    // Guess the path relative to the root of the site, unless the generator version used a
+    // different one
+    VersionedRoot(&'static str),
+    // Guess using a method of FeedFinder, for rules too complex to express in a table
+    Handler(GuessHandler),
+}
+
+#[derive(Debug, PartialEq)]
+pub(crate) enum GuessHandler {
+    Pelican,
+    Gatsby,
+    Nikola,
+    Discourse,
+    Phpbb,
+    Vbulletin,
+    Mediawiki,
+    Dokuwiki,
+    Mastodon,
+    Pleroma,
+    Pixelfed,
+    Writefreely,
+    Tumblr,
+    Wordpress,
+    Nitter,
+    Lemmy,
+    Shopify,
+    Weebly,
+    Livejournal,
+    Dreamwidth,
+    Typepad,
+    Grav,
+    Buttondown,
+    Mailchimp,
+    Hugo,
+    Forem,
+    Medium,
+}
+
+#[cfg(any(not(feature = "toml-rules"), test))]
+impl GuessRule {
+    const fn new(name: &'static str, action: GuessAction) -> Self {
+        GuessRule {
+            name,
+            generators: &[],
+            markup: &[],
+            markup_all: &[],
+            hosts: &[],
+            action,
+        }
+    }
+}
+
+#[cfg(feature = "toml-rules")]
+include!(concat!(env!("OUT_DIR"), "/rules.rs"));
+
+#[cfg(not(feature = "toml-rules"))]
+pub(crate) use self::BUILTIN_RULES as RULES;
+#[cfg(feature = "toml-rules")]
+pub(crate) use self::TOML_RULES as RULES;
+
+#[cfg(any(not(feature = "toml-rules"), test))]
+pub(crate) const BUILTIN_RULES: &[GuessRule] = &[
+    GuessRule {
+        generators: &["hexo"],
+        ..GuessRule::new("hexo", GuessAction::Segments(&["atom.xml", "rss2.xml"]))
+    },
+    GuessRule {
+        generators: &["pelican"],
+        markup_all: &["/theme/css/", "pelican"],
+        ..GuessRule::new("pelican", GuessAction::Handler(GuessHandler::Pelican))
+    },
+    GuessRule {
+        generators: &["grav"],
+        ..GuessRule::new("grav", GuessAction::Handler(GuessHandler::Grav))
+    },
+    GuessRule {
+        generators: &["zola"],
+        ..GuessRule::new("zola", GuessAction::Segments(&["atom.xml", "rss.xml"]))
+    },
+    // The official starter uses feed/feed.xml, others tend to use feed.xml
+    GuessRule {
+        generators: &["eleventy"],
+        ..GuessRule::new(
+            "eleventy",
+            GuessAction::Segments(&["feed/feed.xml", "feed.xml"]),
+        )
+    },
+    GuessRule {
+        markup: &["id=\"___gatsby\"", "/page-data/"],
+        ..GuessRule::new("gatsby", GuessAction::Handler(GuessHandler::Gatsby))
+    },
+    GuessRule {
+        generators: &["nikola"],
+        ..GuessRule::new("nikola", GuessAction::Handler(GuessHandler::Nikola))
+    },
+    // Docs are often hosted under a project path so walk the path to find the root
+    GuessRule {
+        generators: &["mkdocs"],
+        markup: &["mkdocs/js"],
+        ..GuessRule::new(
+            "mkdocs",
+            GuessAction::Segments(&["feed_rss_created.xml", "feed_rss_updated.xml"]),
+        )
+    },
+    // Pleroma and Akkoma pages also link to ActivityPub actors so need to come before Mastodon
+    GuessRule {
+        generators: &["pleroma", "akkoma"],
+        markup: &["to use pleroma", "to use akkoma", "pleroma-fe", "akkoma-fe"],
+        ..GuessRule::new("pleroma", GuessAction::Handler(GuessHandler::Pleroma))
+    },
+    GuessRule {
+        generators: &["pixelfed"],
+        markup: &["/js/pixelfed"],
+        ..GuessRule::new("pixelfed", GuessAction::Handler(GuessHandler::Pixelfed))
+    },
+    // The site name or the footer link to the source code
+    GuessRule {
+        generators: &["nitter"],
+        markup: &["content=\"nitter\"", "github.com/zedeus/nitter"],
+        ..GuessRule::new("nitter", GuessAction::Handler(GuessHandler::Nitter))
+    },
+    // The web app's scripts and footer link to the project
+    GuessRule {
+        markup: &["lemmy-js-client", "github.com/lemmynet"],
+        ..GuessRule::new("lemmy", GuessAction::Handler(GuessHandler::Lemmy))
+    },
+    // DEV, and other Forem communities, which describe themselves with forem: meta tags
+    GuessRule {
+        markup: &["forem:domain"],
+        hosts: &["dev.to"],
+        ..GuessRule::new("forem", GuessAction::Handler(GuessHandler::Forem))
+    },
+    // The web app's root element, or a profile or post that links to its ActivityPub actor
+    GuessRule {
+        markup: &["id=\"mastodon\""],
+        markup_all: &["application/activity+json", "/@"],
+        ..GuessRule::new("mastodon", GuessAction::Handler(GuessHandler::Mastodon))
+    },
+    GuessRule {
+        generators: &["writefreely", "write.as"],
+        hosts: &["write.as"],
+        ..GuessRule::new(
+            "writefreely",
+            GuessAction::Handler(GuessHandler::Writefreely),
+        )
+    },
+    // The footer links to bearblog.dev with the text "Made with Bear"
+    GuessRule {
+        markup: &["made with bear", "href=\"https://bearblog.dev"],
+        hosts: &["bearblog.dev"],
+        ..GuessRule::new("bearblog", GuessAction::Root("/feed/"))
+    },
+    // Hashnode blogs on custom domains load their assets from cdn.hashnode.com. Series and tag
+    // pages don't have feeds of their own.
+    GuessRule {
+        markup: &["hashnode.com"],
+        hosts: &[".hashnode.dev"],
+        ..GuessRule::new("hashnode", GuessAction::Root("/rss.xml"))
+    },
+    GuessRule {
+        generators: &["discourse"],
+        markup: &["discourse-cdn", "data-discourse-setup"],
+        ..GuessRule::new("discourse", GuessAction::Handler(GuessHandler::Discourse))
+    },
+    GuessRule {
+        markup: &["viewforum.php?f="],
+        markup_all: &["powered by", "phpbb"],
+        ..GuessRule::new("phpbb", GuessAction::Handler(GuessHandler::Phpbb))
+    },
+    GuessRule {
+        generators: &["vbulletin"],
+        markup: &["powered by vbulletin"],
+        ..GuessRule::new("vbulletin", GuessAction::Handler(GuessHandler::Vbulletin))
+    },
+    GuessRule {
+        generators: &["mediawiki"],
+        markup: &["poweredby_mediawiki"],
+        ..GuessRule::new("mediawiki", GuessAction::Handler(GuessHandler::Mediawiki))
+    },
+    GuessRule {
+        generators: &["dokuwiki"],
+        markup: &["lib/exe/"],
+        ..GuessRule::new("dokuwiki", GuessAction::Handler(GuessHandler::Dokuwiki))
+    },
+    // Dynamic views themes render in JavaScript, leaving only the host on blogspot.com
+    GuessRule {
+        generators: &["blogger"],
+        markup: &["www.blogger.com/static/", "www.blogger.com/feeds/"],
+        hosts: &[".blogspot.com"],
+        ..GuessRule::new("blogger", GuessAction::Root("/feeds/posts/default"))
+    },
+    GuessRule {
+        markup: &["cdn.shopify.com", "shopify.theme"],
+        ..GuessRule::new("shopify", GuessAction::Handler(GuessHandler::Shopify))
+    },
+    GuessRule {
+        hosts: &[".livejournal.com"],
+        ..GuessRule::new(
+            "livejournal",
+            GuessAction::Handler(GuessHandler::Livejournal),
+        )
+    },
+    GuessRule {
+        hosts: &[".dreamwidth.org"],
+        ..GuessRule::new("dreamwidth", GuessAction::Handler(GuessHandler::Dreamwidth))
+    },
+    GuessRule {
+        markup: &["static.typepad.com"],
+        hosts: &[".typepad.com"],
+        ..GuessRule::new("typepad", GuessAction::Handler(GuessHandler::Typepad))
+    },
+    GuessRule {
+        markup: &["weebly.com", "_w.configdomain"],
+        ..GuessRule::new("weebly", GuessAction::Handler(GuessHandler::Weebly))
+    },
+    // Publications on custom domains load their assets from Medium's CDN
+    GuessRule {
+        markup: &["cdn-client.medium.com"],
+        hosts: &["medium.com"],
+        ..GuessRule::new("medium", GuessAction::Handler(GuessHandler::Medium))
+    },
+    // Newsletters, which publish their archives as feeds
+    GuessRule {
+        markup: &["substackcdn.com"],
+        hosts: &[".substack.com"],
+        ..GuessRule::new("substack", GuessAction::Root("/feed"))
+    },
+    GuessRule {
+        hosts: &["buttondown.email", "buttondown.com"],
+        ..GuessRule::new("buttondown", GuessAction::Handler(GuessHandler::Buttondown))
+    },
+    GuessRule {
+        hosts: &[".campaign-archive.com"],
+        ..GuessRule::new("mailchimp", GuessAction::Handler(GuessHandler::Mailchimp))
+    },
+    // Custom domains load their assets from tumblr.com, JavaScript themes may only have the host
+    GuessRule {
+        markup: &["tumblr.com"],
+        hosts: &["tumblr.com"],
+        ..GuessRule::new("tumblr", GuessAction::Handler(GuessHandler::Tumblr))
+    },
+    // Minimal themes and AMP views on WordPress.com may not mention WordPress at all
+    GuessRule {
+        markup: &["wordpress"],
+        hosts: &[".wordpress.com"],
+        ..GuessRule::new("wordpress", GuessAction::Handler(GuessHandler::Wordpress))
+    },
+    GuessRule {
+        markup: &["hugo"],
+        ..GuessRule::new("hugo", GuessAction::Handler(GuessHandler::Hugo))
+    },
+    // Zola sites without a generator tag can still be spotted by their search index
+    GuessRule {
+        markup: &["elasticlunr"],
+        ..GuessRule::new(
+            "elasticlunr",
+            GuessAction::Segments(&["atom.xml", "rss.xml"]),
+        )
+    },
+    // jekyll-feed, which GitHub Pages uses by default, writes feed.xml. GitLab Pages sites are
+    // just as likely to be from a static site generator.
+    GuessRule {
+        markup: &["jekyll"],
+        hosts: &["github.io", "gitlab.io"],
+        ..GuessRule::new("jekyll", GuessAction::Segments(&["feed.xml", "atom.xml"]))
+    },
+    GuessRule {
+        markup: &["ghost"],
+        ..GuessRule::new("ghost", GuessAction::VersionedRoot("/rss/"))
+    },
+];